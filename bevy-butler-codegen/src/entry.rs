@@ -0,0 +1,167 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, ToTokens};
+use syn::{Expr, ExprClosure, Ident, Path};
+
+use crate::ButlerPhase;
+
+/// Generates a `bevy_butler::registry::ButlerEntry` and submits it to the registry.
+///
+/// ```rust
+/// # use bevy_butler_codegen::{ButlerEntry, ButlerPhase};
+/// # use syn::parse_quote;
+/// let plugin = parse_quote!(MyPlugin);
+/// let tokens = ButlerEntry::new("my_widget", &plugin, quote::quote!(MyWidget))
+///     .phase(ButlerPhase::Finish)
+///     .generate(&parse_quote! {
+///         |app| { app.init_resource::<MyWidget>(); }
+///     });
+/// ```
+#[derive(Clone)]
+pub struct ButlerEntry {
+    kind: String,
+    plugin: Path,
+    name: String,
+    phase: ButlerPhase,
+    sub_app: Option<Expr>,
+    schedules: Vec<Expr>,
+    stage: Option<TokenStream>,
+    hash_bytes: String,
+}
+
+impl ButlerEntry {
+    /// Starts a new entry for `plugin`.
+    ///
+    /// `kind` identifies the macro generating the entry and must be a valid identifier fragment,
+    /// like `"system"` or `"my_crate_widget"`. `name` is a human-readable name of the registered item.
+    /// Both are part of the generated static's name, and are reported in `ButlerEntryInfo`.
+    pub fn new(kind: &str, plugin: &Path, name: impl ToTokens) -> Self {
+        ButlerEntry {
+            kind: kind.to_string(),
+            plugin: plugin.clone(),
+            name: name.to_token_stream().to_string(),
+            phase: ButlerPhase::Build,
+            sub_app: None,
+            schedules: Vec::new(),
+            stage: None,
+            hash_bytes: String::new(),
+        }
+    }
+
+    /// Sets the phase the entry runs in.
+    pub fn phase(mut self, phase: ButlerPhase) -> Self {
+        self.phase = phase;
+        self
+    }
+
+    /// Redirects the entry to the `SubApp` with the given label, if any.
+    ///
+    /// The step's `app` argument is shadowed with the `&mut SubApp`, so the
+    /// step must only use methods that both `App` and `SubApp` provide.
+    pub fn sub_app(mut self, sub_app: Option<&Expr>) -> Self {
+        self.sub_app = sub_app.cloned();
+        self
+    }
+
+    /// Declares a schedule the entry adds systems to, so `bevy_butler::check` can verify that it runs.
+    /// Should be called once for each schedule.
+    ///
+    /// Entries redirected to a `SubApp` don't declare their schedules, as only the main `App` is checked.
+    pub fn schedule(mut self, schedule: &Expr) -> Self {
+        self.schedules.push(schedule.clone());
+        self
+    }
+
+    /// Runs the entry before the other entries of its phase, except those inserting a `SubApp`.
+    ///
+    /// Meant for entries whose effect must not depend on the order entries are linked in,
+    /// like assigning component ids.
+    pub fn early(mut self) -> Self {
+        self.stage = Some(quote!(Early));
+        self
+    }
+
+    /// Runs the entry before every other entry of its phase, including the early ones.
+    ///
+    /// Meant for entries inserting a `SubApp`, so the other entries can target it.
+    pub fn inserts_sub_app(mut self) -> Self {
+        self.stage = Some(quote!(InsertSubApps));
+        self
+    }
+
+    /// Adds extra tokens to the hash that makes the generated static unique.
+    ///
+    /// Should be used for any argument that allows the same item to be registered twice to the same plugin,
+    /// like a schedule.
+    pub fn hash(mut self, tokens: impl ToTokens) -> Self {
+        self.hash_bytes += &tokens.to_token_stream().to_string();
+        self
+    }
+
+    /// The identifier of the generated static.
+    pub fn static_ident(&self) -> Ident {
+        let hash_bytes = [
+            self.kind.as_str(),
+            &self.plugin.to_token_stream().to_string(),
+            &self.name,
+            self.phase.fn_name(),
+            &self.sub_app.to_token_stream().to_string(),
+            &self.hash_bytes,
+        ]
+        .concat();
+        format_ident!("_butler_{}_{}", self.kind, sha256::digest(hash_bytes))
+    }
+
+    /// Generates the entry, running `step` on the plugin's `App` (or `SubApp`).
+    ///
+    /// `step` must be a non-capturing closure with a single `app` argument.
+    pub fn generate(&self, step: &ExprClosure) -> TokenStream {
+        let static_ident = self.static_ident();
+        let plugin = &self.plugin;
+        let phase = self.phase;
+        let kind = &self.kind;
+        let name = &self.name;
+
+        let schedules = &self.schedules;
+        let schedules = (!schedules.is_empty() && self.sub_app.is_none()).then(|| {
+            quote! {
+                .with_schedules({
+                    const SCHEDULES: &[fn() -> ::bevy_butler::registry::ButlerSchedule] = &[#(|| {
+                        use ::bevy_butler::registry::ButlerScheduleProbeFallback as _;
+                        ::bevy_butler::registry::ButlerScheduleProbe(&#schedules).schedule()
+                    }),*];
+                    SCHEDULES
+                })
+            }
+        });
+        let stage = self
+            .stage
+            .as_ref()
+            .map(|stage| quote!(.in_stage(::bevy_butler::registry::ButlerStage::#stage)));
+
+        let mut step = step.clone();
+        if let Some(sub_app) = &self.sub_app {
+            let body = &step.body;
+            let body: Expr = syn::parse_quote! {{
+                let app = ::bevy_butler::registry::butler_sub_app_mut(app, #sub_app);
+                #body
+            }};
+            *step.body = body;
+        }
+
+        quote! {
+            ::bevy_butler::butler_entry!(#static_ident, ::bevy_butler::registry::ButlerEntry::new(
+                <#plugin as ::bevy_butler::registry::ButlerPluginMarker>::butler_plugin_marker,
+                #step
+            )
+            .in_phase(#phase)
+            #schedules
+            #stage
+            .with_info(::bevy_butler::registry::ButlerEntryInfo {
+                kind: #kind,
+                name: #name,
+                file: ::core::file!(),
+                line: ::core::line!(),
+            }));
+        }
+    }
+}
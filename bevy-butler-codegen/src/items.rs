@@ -0,0 +1,33 @@
+use syn::{Error, Ident, Item, UseTree};
+
+/// Returns the identifier imported by a `use` tree.
+pub fn get_use_path(tree: &UseTree) -> syn::Result<&Ident> {
+    match tree {
+        UseTree::Path(path) => get_use_path(&path.tree),
+        UseTree::Name(name) => Ok(&name.ident),
+        UseTree::Rename(rename) => Ok(&rename.rename),
+        UseTree::Group(_) | UseTree::Glob(_) => {
+            Err(syn::Error::new_spanned(tree, "Expected a path"))
+        }
+    }
+}
+
+/// Returns the identifier of an annotated type: a struct, enum, type alias or use statement.
+pub fn get_struct_or_enum_ident(item: &Item) -> syn::Result<&Ident> {
+    match item {
+        Item::Struct(i) => Ok(&i.ident),
+        Item::Enum(i) => Ok(&i.ident),
+        Item::Use(i) => get_use_path(&i.tree),
+        Item::Type(i) => Ok(&i.ident),
+        other => Err(Error::new_spanned(other, "Expected a struct, enum, type alias or use statement")),
+    }
+}
+
+/// Returns the identifier of an annotated function or use statement.
+pub fn get_fn_ident(item: &Item) -> syn::Result<&Ident> {
+    match item {
+        Item::Fn(i) => Ok(&i.sig.ident),
+        Item::Use(i) => get_use_path(&i.tree),
+        other => Err(Error::new_spanned(other, "Expected a function or use statement")),
+    }
+}
@@ -0,0 +1,59 @@
+//! Code generation helpers for writing [bevy-butler](https://docs.rs/bevy-butler) registration macros.
+//!
+//! This crate is meant to be used from a proc macro crate. The code it generates refers to
+//! `::bevy_butler`, so crates using the resulting macros must depend on `bevy-butler` directly.
+//!
+//! # Features
+//! - `codegen` (default): everything but [`ButlerPhase`], which is always available.
+//! - `deluxe` (default): implements `deluxe::ParseMetaItem` for [`ButlerPhase`], as used in the
+//!   example below. This ties the crate's public API to the major version of `deluxe` it depends
+//!   on, so updating `deluxe` is a breaking change. Crates that parse their arguments another way
+//!   can disable it.
+//!
+//! ```rust
+//! use bevy_butler_codegen::{get_struct_or_enum_ident, ButlerEntry, ButlerPhase};
+//! use proc_macro2::TokenStream;
+//! use quote::quote;
+//! use syn::{Expr, Item, Path};
+//!
+//! #[derive(deluxe::ParseMetaItem)]
+//! struct WidgetAttr {
+//!     plugin: Path,
+//!     sub_app: Option<Expr>,
+//!     #[deluxe(default)]
+//!     phase: ButlerPhase,
+//! }
+//!
+//! // Called from `#[proc_macro_attribute] pub fn register_widget(...)`
+//! fn register_widget(attr: TokenStream, item: TokenStream) -> syn::Result<TokenStream> {
+//!     let attr: WidgetAttr = deluxe::parse2(attr)?;
+//!     let item: Item = syn::parse2(item)?;
+//!     let ident = get_struct_or_enum_ident(&item)?;
+//!
+//!     let entry = ButlerEntry::new("my_crate_widget", &attr.plugin, ident)
+//!         .phase(attr.phase)
+//!         .sub_app(attr.sub_app.as_ref())
+//!         .generate(&syn::parse_quote! {
+//!             |app| { app.init_resource::<#ident>(); }
+//!         });
+//!
+//!     Ok(quote! {
+//!         #item
+//!
+//!         #entry
+//!     })
+//! }
+//! ```
+
+#[cfg(feature = "codegen")]
+mod entry;
+#[cfg(feature = "codegen")]
+pub use entry::ButlerEntry;
+
+#[cfg(feature = "codegen")]
+mod items;
+#[cfg(feature = "codegen")]
+pub use items::*;
+
+mod phase;
+pub use phase::ButlerPhase;
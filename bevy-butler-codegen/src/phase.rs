@@ -0,0 +1,68 @@
+/// The `Plugin` method an entry is run from.
+///
+/// This is the same type as `bevy_butler::registry::ButlerPhase`. With the `deluxe` feature, it's
+/// parsed from `phase = build | finish | cleanup` when used as a `deluxe` field. With the `codegen`
+/// feature, it expands to the matching variant.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ButlerPhase {
+    /// Run from `Plugin::build`.
+    #[default]
+    Build,
+    /// Run from `Plugin::finish`, after every plugin has been built.
+    Finish,
+    /// Run from `Plugin::cleanup`, after every plugin has finished.
+    Cleanup,
+}
+
+impl ButlerPhase {
+    /// The name of the `Plugin` method this phase runs from
+    pub fn fn_name(&self) -> &'static str {
+        match self {
+            Self::Build => "build",
+            Self::Finish => "finish",
+            Self::Cleanup => "cleanup",
+        }
+    }
+}
+
+#[cfg(feature = "deluxe")]
+mod parse {
+    use deluxe::{ParseMetaItem, ParseMode};
+    use syn::parse::ParseStream;
+    use syn::Ident;
+
+    use super::ButlerPhase;
+
+    impl ParseMetaItem for ButlerPhase {
+        fn parse_meta_item(input: ParseStream, _mode: ParseMode) -> deluxe::Result<Self> {
+            let ident: Ident = input.parse()?;
+            match ident.to_string().as_str() {
+                "build" => Ok(Self::Build),
+                "finish" => Ok(Self::Finish),
+                "cleanup" => Ok(Self::Cleanup),
+                _ => Err(syn::Error::new_spanned(
+                    ident,
+                    "Expected `build`, `finish` or `cleanup`",
+                )),
+            }
+        }
+    }
+}
+
+#[cfg(feature = "codegen")]
+mod tokens {
+    use proc_macro2::TokenStream;
+    use quote::{quote, ToTokens};
+
+    use super::ButlerPhase;
+
+    impl ToTokens for ButlerPhase {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            tokens.extend(match self {
+                Self::Build => quote!(::bevy_butler::registry::ButlerPhase::Build),
+                Self::Finish => quote!(::bevy_butler::registry::ButlerPhase::Finish),
+                Self::Cleanup => quote!(::bevy_butler::registry::ButlerPhase::Cleanup),
+            });
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use proc_macro::TokenStream as TokenStream1;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{quote, quote_spanned};
+use structs::AddComputedStateAttr;
+use syn::Item;
+
+use crate::insert_state::state_debug_tokens;
+use crate::utils::{get_struct_or_enum_ident, ButlerEntry};
+
+pub mod structs;
+
+pub fn macro_impl(attr: TokenStream1, body: TokenStream1) -> syn::Result<TokenStream2> {
+    let attr: AddComputedStateAttr = deluxe::parse(attr)?;
+    let item: Item = syn::parse(body)?;
+    let ident = get_struct_or_enum_ident(&item)?;
+    let generics = &attr.generics;
+    let debug = state_debug_tokens(&attr.debug, &quote!(#ident #generics), false)?;
+
+    // Point a missing `ComputedStates` impl at the annotated type rather than the registry entry
+    let computed_check = quote_spanned! { ident.span() =>
+        const _: () = ::bevy_butler::__internal::assert_computed_states::<#ident #generics>();
+    };
+
+    let register_block = ButlerEntry::new("computed_state", &attr.plugin, quote!(#ident #generics))
+        .phase(attr.phase)
+        .sub_app(attr.sub_app.as_ref())
+        .generate(&syn::parse_quote! {
+            |app| { ::bevy_butler::__internal::bevy_state::app::AppExtStates::add_computed_state::<#ident #generics>(app); #debug }
+        });
+
+    Ok(quote! {
+        #item
+
+        #computed_check
+
+        #register_block
+    })
+}
@@ -0,0 +1,16 @@
+use deluxe::ParseMetaItem;
+use syn::{AngleBracketedGenericArguments, Expr, Path};
+
+use crate::insert_state::structs::StateDebugAttr;
+use crate::utils::ButlerPhase;
+
+#[derive(ParseMetaItem)]
+pub struct AddComputedStateAttr {
+    pub plugin: Path,
+    pub generics: Option<AngleBracketedGenericArguments>,
+    pub sub_app: Option<Expr>,
+    #[deluxe(default)]
+    pub phase: ButlerPhase,
+    #[deluxe(flatten)]
+    pub debug: StateDebugAttr,
+}
@@ -1,10 +1,10 @@
 use proc_macro::TokenStream as TokenStream1;
 use proc_macro2::TokenStream as TokenStream2;
-use quote::{format_ident, quote, ToTokens};
+use quote::quote;
 use structs::*;
 use syn::Item;
 
-use crate::utils::{butler_plugin_entry_block, get_struct_or_enum_ident};
+use crate::utils::{get_struct_or_enum_ident, ButlerEntry};
 
 pub(crate) mod structs;
 
@@ -16,18 +16,12 @@ pub(crate) fn macro_impl(attr: TokenStream1, body: TokenStream1) -> syn::Result<
     let plugin = &attr.plugin;
     let generics = &attr.generics;
 
-    let mut hash_bytes = event_ident.to_string();
-    hash_bytes += &plugin.to_token_stream().to_string();
-    hash_bytes += &generics.to_token_stream().to_string();
-    let static_ident = format_ident!("_butler_event_{}", sha256::digest(hash_bytes));
-
-    let register_block = butler_plugin_entry_block(
-        &static_ident,
-        &attr.plugin,
-        &syn::parse_quote! {
+    let register_block = ButlerEntry::new("event", plugin, quote!(#event_ident #generics))
+        .phase(attr.phase)
+        .sub_app(attr.sub_app.as_ref())
+        .generate(&syn::parse_quote! {
             |app| { app.add_message::<#event_ident #generics>(); }
-        },
-    );
+        });
 
     Ok(quote! {
         #item
@@ -1,8 +1,13 @@
 use deluxe::ParseMetaItem;
-use syn::{AngleBracketedGenericArguments, Path};
+use syn::{AngleBracketedGenericArguments, Expr, Path};
+
+use crate::utils::ButlerPhase;
 
 #[derive(ParseMetaItem)]
 pub(crate) struct EventAttr {
     pub plugin: Path,
     pub generics: Option<AngleBracketedGenericArguments>,
+    pub sub_app: Option<Expr>,
+    #[deluxe(default)]
+    pub phase: ButlerPhase,
 }
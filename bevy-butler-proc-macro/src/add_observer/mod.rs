@@ -1,10 +1,10 @@
 use proc_macro::TokenStream as TokenStream1;
-use proc_macro2::TokenStream as TokenStream2;
-use quote::{format_ident, quote, ToTokens};
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
 use structs::ObserverAttr;
-use syn::{Expr, Ident, Item};
+use syn::{Error, Expr, ExprCall, ExprClosure, Ident, Item};
 
-use crate::utils::{butler_plugin_entry_block, get_fn_ident};
+use crate::utils::{get_fn_ident, ButlerEntry};
 
 pub(crate) mod structs;
 
@@ -26,19 +26,138 @@ pub(crate) fn macro_impl(attr: TokenStream1, body: TokenStream1) -> syn::Result<
     let plugin = &attr.plugin;
     let obsrv_expr = parse_observer(&attr, ident);
 
-    let mut hash_bytes = "observer".to_string();
-    hash_bytes += &plugin.to_token_stream().to_string();
-    hash_bytes += &obsrv_expr.to_token_stream().to_string();
+    // Observers don't have run conditions, so `run_if` wraps the observer system instead
+    let (conditions, transforms): (Vec<&ExprCall>, Vec<&ExprCall>) =
+        attr.transforms.0.iter().partition(|transform| {
+            matches!(&*transform.func, Expr::Path(path) if path.path.is_ident("run_if"))
+        });
+    let run_if = |mut observer: Expr| {
+        for condition in &conditions {
+            let args = &condition.args;
+            observer = syn::parse_quote! {
+                ::bevy_butler::__internal::observer_run_if(#observer, #args)
+            };
+        }
+        observer
+    };
 
-    let static_ident = format_ident!("_butler_observer_{}", sha256::digest(hash_bytes));
+    // Route errors to the entry's or the plugin's error handler, if any
+    let error_handler = match &attr.error_handler {
+        Some(error_handler) => quote! {
+            ::core::option::Option::<::bevy_butler::__internal::ButlerErrorHandler>::Some(#error_handler)
+        },
+        None => quote!(<#plugin as ::bevy_butler::__internal::ButlerPlugin>::ERROR_HANDLER),
+    };
+    let routed = run_if(syn::parse_quote! {
+        ::bevy_butler::__internal::route_errors(
+            #obsrv_expr,
+            error_handler,
+            ::core::stringify!(#plugin),
+            ::bevy_butler::registry::ButlerEntryInfo {
+                kind: "observer",
+                name: ::core::stringify!(#ident),
+                file: ::core::file!(),
+                line: ::core::line!(),
+            },
+            true,
+        )
+    });
+    let plain = run_if(obsrv_expr.clone());
+    let observer = quote! {
+        match #error_handler {
+            ::core::option::Option::Some(error_handler) => {
+                ::bevy_butler::__internal::bevy_ecs::observer::Observer::new( #routed )
+            }
+            ::core::option::Option::None => {
+                ::bevy_butler::__internal::bevy_ecs::observer::Observer::new( #plain )
+            }
+        } #(. #transforms)*
+    };
+    let name = attr
+        .name
+        .as_ref()
+        .map(|name| quote!(::bevy_butler::__internal::bevy_ecs::name::Name::new(#name),));
+    let components = attr.components.iter().flat_map(|components| &components.0);
+    let extras = quote!(#name #(#components,)*);
+
+    let targets: Vec<&str> = [
+        attr.scoped_to.as_ref().map(|_| "scoped_to"),
+        attr.watch.as_ref().map(|_| "watch"),
+        attr.entities.as_ref().map(|_| "entities"),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+    if let [first, second, ..] = targets[..] {
+        return Err(Error::new(
+            Span::call_site(),
+            format!("`{first}` and `{second}` can't be used together"),
+        ));
+    }
 
-    let register_block = butler_plugin_entry_block(
-        &static_ident,
-        plugin,
-        &syn::parse_quote! {
-            |app| { app.add_observer( #obsrv_expr ); }
+    let entry_expr: ExprClosure = match (&attr.scoped_to, &attr.watch, &attr.entities) {
+        // Spawn the observer entity on entering the state, and despawn it again on exit
+        (Some(state), _, _) => syn::parse_quote! {
+            |app| {
+                app.add_systems(
+                    ::bevy_butler::__internal::bevy_state::prelude::OnEnter(#state),
+                    |mut commands: ::bevy_butler::__internal::bevy_ecs::system::Commands| {
+                        commands.spawn((
+                            #observer,
+                            ::bevy_butler::__internal::ButlerScopedObserver(#state),
+                            #extras
+                        ));
+                    },
+                );
+                app.add_systems(
+                    ::bevy_butler::__internal::bevy_state::prelude::OnExit(#state),
+                    ::bevy_butler::__internal::despawn_scoped_observers(#state),
+                );
+            }
         },
-    );
+        // Spawn an observer for every entity that gains the component
+        (_, Some(component), _) => syn::parse_quote! {
+            |app| {
+                ::bevy_butler::__internal::watch_component::<#component>(
+                    app.world_mut(),
+                    |commands, entity| {
+                        commands.spawn((
+                            #observer.with_entity(entity),
+                            #extras
+                        )).id()
+                    },
+                );
+            }
+        },
+        // Find the entities once startup has spawned them, rather than while plugins are still being built
+        (_, _, Some(entities)) => syn::parse_quote! {
+            |app| {
+                app.add_systems(
+                    ::bevy_butler::__internal::bevy_app::PostStartup,
+                    |world: &mut ::bevy_butler::__internal::bevy_ecs::world::World| {
+                        let entities = (#entities)(world);
+                        world.spawn((
+                            #observer.with_entities(entities),
+                            #extras
+                        ));
+                    },
+                );
+            }
+        },
+        _ => syn::parse_quote! {
+            |app| { app.world_mut().spawn(( #observer, #extras )); }
+        },
+    };
+
+    let register_block = ButlerEntry::new("observer", plugin, &obsrv_expr)
+        .hash(&attr.scoped_to)
+        .hash(&attr.watch)
+        .hash(&attr.entities)
+        .hash(&observer)
+        .hash(&extras)
+        .phase(attr.phase)
+        .sub_app(attr.sub_app.as_ref())
+        .generate(&entry_expr);
 
     Ok(quote! {
         #item
@@ -1,8 +1,22 @@
 use deluxe::ParseMetaItem;
-use syn::{AngleBracketedGenericArguments, Path};
+use syn::{AngleBracketedGenericArguments, Expr, Path, Type};
+
+use crate::add_system::structs::TransformList;
+use crate::utils::{ButlerPhase, ExprList};
 
 #[derive(ParseMetaItem)]
 pub(crate) struct ObserverAttr {
     pub plugin: Path,
     pub generics: Option<AngleBracketedGenericArguments>,
+    pub scoped_to: Option<Expr>,
+    pub watch: Option<Type>,
+    pub entities: Option<Expr>,
+    pub name: Option<Expr>,
+    pub components: Option<ExprList>,
+    pub error_handler: Option<Expr>,
+    pub sub_app: Option<Expr>,
+    #[deluxe(default)]
+    pub phase: ButlerPhase,
+    #[deluxe(rest)]
+    pub transforms: TransformList,
 }
@@ -4,7 +4,7 @@ use quote::{format_ident, quote, ToTokens};
 use structs::{AddPluginAttr, ButlerTarget};
 use syn::{parse, parse_quote, Fields, Item, ItemStruct};
 
-use crate::utils::{butler_plugin_entry_block, butler_plugin_group_entry_block, get_struct_or_enum_ident};
+use crate::utils::{butler_plugin_group_entry_block, get_struct_or_enum_ident, ButlerEntry};
 
 pub mod structs;
 
@@ -40,7 +40,8 @@ pub(crate) fn macro_impl(attr: TokenStream1, body: TokenStream1) -> syn::Result<
 
     let register_block = match attr.target {
         ButlerTarget::Plugin(target) => {
-            butler_plugin_entry_block(&static_ident, &target, &register)
+            ButlerEntry::new("add_plugin", &target, quote!(#plugin_ident #generics))
+                .generate(&register)
         }
         ButlerTarget::PluginGroup(group) => {
             butler_plugin_group_entry_block(&static_ident, &group, &register)
@@ -8,7 +8,7 @@ use syn::{
 
 use crate::{
     add_plugin::structs::ButlerTarget,
-    utils::{butler_plugin_entry_block, butler_plugin_group_entry_block, get_struct_or_enum_ident},
+    utils::{butler_plugin_group_entry_block, get_struct_or_enum_ident, ButlerEntry},
 };
 
 pub(crate) mod structs;
@@ -60,7 +60,8 @@ pub(crate) fn macro_impl(attr: TokenStream1, body: TokenStream1) -> syn::Result<
                 app.add_plugins(plugin);
             }};
 
-            butler_plugin_entry_block(&static_ident, &target, &register)
+            ButlerEntry::new("add_plugin_group", &target, quote!(#plugin_ident #generics))
+                .generate(&register)
         }
         ButlerTarget::PluginGroup(target) => {
             let register = parse_quote! { |builder| {
@@ -0,0 +1,121 @@
+use proc_macro::TokenStream as TokenStream1;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
+use structs::AddScheduleAttr;
+use syn::{parse_quote, Error, Expr, Item};
+
+use crate::utils::{default_label, ButlerEntry};
+
+pub(crate) mod structs;
+
+pub(crate) fn macro_impl(attr: TokenStream1, body: TokenStream1) -> syn::Result<TokenStream2> {
+    let mut attr: AddScheduleAttr = deluxe::parse(attr)?;
+    let item: Item = syn::parse(body)?;
+
+    if attr.label.is_none() {
+        attr.label = Some(default_label(&item)?);
+    }
+    let label = &attr.label;
+
+    let executor = attr.executor.as_ref().map(|executor| {
+        // Allow `executor = SingleThreaded` as shorthand for `ExecutorKind::SingleThreaded`
+        let executor: Expr = match executor {
+            Expr::Path(path) if path.path.get_ident().is_some() => {
+                parse_quote!(::bevy_butler::__internal::bevy_ecs::schedule::ExecutorKind::#path)
+            }
+            executor => executor.clone(),
+        };
+        quote! { schedule.set_executor_kind(#executor); }
+    });
+    let build_settings = attr.build_settings.as_ref().map(|settings| {
+        quote! { schedule.set_build_settings(#settings); }
+    });
+
+    if attr.startup.is_set() && attr.fixed.is_set() {
+        return Err(Error::new(
+            Span::call_site(),
+            "`startup` and `fixed` can't be used together",
+        ));
+    }
+
+    let startup = attr.startup.is_set();
+    let position = match (&attr.after, &attr.before) {
+        (Some(_), Some(before)) => {
+            return Err(Error::new_spanned(
+                before,
+                "`after` and `before` can't be used together",
+            ))
+        }
+        (Some(after), None) if startup => Some((after, quote!(insert_startup_after))),
+        (Some(after), None) => Some((after, quote!(insert_after))),
+        (None, Some(before)) if startup => Some((before, quote!(insert_startup_before))),
+        (None, Some(before)) => Some((before, quote!(insert_before))),
+        (None, None) => None,
+    };
+    if let (true, Some((position, _))) = (attr.manual.is_set(), &position) {
+        return Err(Error::new_spanned(
+            position,
+            "A `manual` schedule can't be inserted into the schedule order",
+        ));
+    }
+    if let (Some(_), Some((position, _))) = (&attr.sub_app, &position) {
+        return Err(Error::new_spanned(
+            position,
+            "A `sub_app` schedule can't be inserted into the schedule order, which only the main `App` has",
+        ));
+    }
+    let manual = attr.manual.is_set().then(|| {
+        quote! {
+            app.world_mut()
+                .get_resource_or_init::<::bevy_butler::check::ManuallyRunSchedules>()
+                .insert(#label);
+        }
+    });
+
+    let order = if attr.fixed.is_set() {
+        quote!(FixedMainScheduleOrder)
+    } else {
+        quote!(MainScheduleOrder)
+    };
+    let insert = position.map(|(position, method)| {
+        quote! {
+            match app.world_mut().get_resource_mut::<::bevy_butler::__internal::bevy_app::#order>() {
+                ::core::option::Option::Some(mut order) => order.#method(#position, #label),
+                ::core::option::Option::None => ::core::panic!(
+                    "`{}` at {}:{} can't be inserted into the schedule order, as the app has no `{}`",
+                    ::core::stringify!(#label),
+                    ::core::file!(),
+                    ::core::line!(),
+                    ::core::stringify!(#order),
+                ),
+            }
+        }
+    });
+
+    let edit = (executor.is_some() || build_settings.is_some()).then(|| {
+        quote! {
+            app.edit_schedule(#label, |schedule| {
+                #executor
+                #build_settings
+            });
+        }
+    });
+
+    let register_block = ButlerEntry::new("schedule", &attr.plugin, label)
+        .phase(attr.phase)
+        .sub_app(attr.sub_app.as_ref())
+        .generate(&parse_quote! {
+            |app| {
+                app.init_schedule(#label);
+                #edit
+                #insert
+                #manual
+            }
+        });
+
+    Ok(quote! {
+        #item
+
+        #register_block
+    })
+}
@@ -0,0 +1,20 @@
+use deluxe::{Flag, ParseMetaItem};
+use syn::{Expr, Path};
+
+use crate::utils::ButlerPhase;
+
+#[derive(ParseMetaItem)]
+pub(crate) struct AddScheduleAttr {
+    pub plugin: Path,
+    pub label: Option<Expr>,
+    pub after: Option<Expr>,
+    pub before: Option<Expr>,
+    pub startup: Flag,
+    pub fixed: Flag,
+    pub manual: Flag,
+    pub executor: Option<Expr>,
+    pub build_settings: Option<Expr>,
+    pub sub_app: Option<Expr>,
+    #[deluxe(default)]
+    pub phase: ButlerPhase,
+}
@@ -1,63 +1,284 @@
 use proc_macro::TokenStream as TokenStream1;
-use proc_macro2::TokenStream as TokenStream2;
-use quote::{format_ident, quote, ToTokens};
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
 use structs::SystemAttr;
-use syn::Expr;
+use structs::TransformList;
+use syn::{AngleBracketedGenericArguments, Error, Expr, ExprCall, Path};
 use syn::{Ident, Item};
 
-use crate::utils::{butler_plugin_entry_block, get_fn_ident};
+use crate::utils::{get_fn_ident, ButlerEntry};
 
 pub mod structs;
 
-pub(crate) fn parse_system(attr: &SystemAttr, ident: &Ident) -> Expr {
-    let generics = attr.generics.clone().map(|mut g| {
+pub(crate) fn parse_system(
+    ident: &Ident,
+    generics: Option<&AngleBracketedGenericArguments>,
+    pipe_in: Option<&Vec<Expr>>,
+) -> Expr {
+    pipe_into(system_path(None, ident, generics), pipe_in)
+}
+
+/// The path to the system function, qualified with `owner` for associated functions
+fn system_path(
+    owner: Option<&Path>,
+    ident: &Ident,
+    generics: Option<&AngleBracketedGenericArguments>,
+) -> Expr {
+    let generics = generics.cloned().map(|mut g| {
         g.colon2_token = Some(Default::default());
         g
     });
-    let transforms = &attr.transforms.0;
-
-    let sys_expr: Expr = syn::parse_quote! {
-        #ident #generics #(. #transforms)*
-    };
+    let owner = owner.map(|owner| quote!(<#owner>::));
+    syn::parse_quote!(#owner #ident #generics)
+}
 
-    match &attr.pipe_in {
+fn pipe_into(system: Expr, pipe_in: Option<&Vec<Expr>>) -> Expr {
+    match pipe_in {
         Some(pipes) if !pipes.is_empty() => {
             let mut iter = pipes.iter();
             let first = iter.next().unwrap();
             syn::parse_quote! {
-                #first #(.pipe(#iter))* .pipe(#sys_expr)
+                #first #(.pipe(#iter))* .pipe(#system)
             }
         }
-        _ => sys_expr,
+        _ => system,
     }
 }
 
-pub(crate) fn macro_impl(attr: TokenStream1, item: TokenStream1) -> syn::Result<TokenStream2> {
-    let attr: SystemAttr = deluxe::parse(attr)?;
-    let input: Item = syn::parse(item)?;
+/// Wraps an `async fn` in a system spawning its future on every run.
+/// Its only argument, if any, is the system's input.
+fn async_system(
+    attr: &SystemAttr,
+    item: &Item,
+    owner: Option<&Path>,
+    ident: &Ident,
+) -> syn::Result<Expr> {
+    let Item::Fn(func) = item else {
+        return Err(Error::new_spanned(
+            item,
+            "`async` can only be used on an `async fn`",
+        ));
+    };
+    if func.sig.asyncness.is_none() {
+        return Err(Error::new_spanned(
+            &func.sig,
+            "`async` can only be used on an `async fn`",
+        ));
+    }
 
-    let sys_ident = get_fn_ident(&input)?;
+    let function = system_path(owner, ident, attr.generics.as_ref());
+    let system = match func.sig.inputs.len() {
+        0 => syn::parse_quote!(::bevy_butler::__internal::spawn_async(#function)),
+        1 => syn::parse_quote!(::bevy_butler::__internal::spawn_async_with_input(#function)),
+        _ => {
+            return Err(Error::new_spanned(
+                &func.sig.inputs,
+                "async systems can only take a single argument, piped in with `pipe_in`",
+            ))
+        }
+    };
+    Ok(pipe_into(system, attr.pipe_in.as_ref()))
+}
 
-    let plugin = &attr.plugin;
-    let schedule = &attr.schedule;
+/// Maps the system's output with `map`, then pipes it through each of `pipe_out`
+fn with_outputs(system: Expr, map: Option<&Expr>, pipe_out: Option<&Vec<Expr>>) -> Expr {
+    let map = map.map(|map| quote!(.map(#map)));
+    let pipe_out = pipe_out.into_iter().flatten();
+    syn::parse_quote! {
+        #system #map #(.pipe(#pipe_out))*
+    }
+}
 
-    let sys_expr = parse_system(&attr, sys_ident);
+/// The transforms running the system on its own timer for `every` or `every_fixed_ticks` if either is given,
+/// along with the statements adding the timer and the system ticking it.
+///
+/// The timer is looked up by a type declared in the entry, so every entry has its own, even for the same `function`.
+fn timer_transforms(
+    attr: &SystemAttr,
+    function: &Expr,
+) -> syn::Result<Option<([ExprCall; 2], TokenStream2)>> {
+    let init = match (&attr.every, &attr.every_fixed_ticks, &attr.initial_delay) {
+        (Some(_), Some(_), _) => {
+            return Err(Error::new(
+                Span::call_site(),
+                "`every` and `every_fixed_ticks` can't be used together",
+            ))
+        }
+        (None, _, Some(delay)) => {
+            return Err(Error::new_spanned(
+                delay,
+                "`initial_delay` requires `every`",
+            ))
+        }
+        (Some(period), None, delay) => {
+            let delay = match delay {
+                Some(delay) => quote!(::core::option::Option::Some(#delay)),
+                None => quote!(::core::option::Option::None),
+            };
+            quote! {
+                ::bevy_butler::__internal::init_every_timer::<ButlerTimerKey, _>(app.world_mut(), &#function, #period, #delay);
+            }
+        }
+        (None, Some(ticks), None) => quote! {
+            ::bevy_butler::__internal::init_fixed_ticks_timer::<ButlerTimerKey, _>(app.world_mut(), &#function, #ticks);
+        },
+        (None, None, None) => return Ok(None),
+    };
+    let schedule = &attr.schedule;
+    let init = quote! {
+        enum ButlerTimerKey {}
+        #init
+        app.add_systems(#schedule, ::bevy_butler::__internal::tick_timer::<ButlerTimerKey>());
+    };
+    let transforms = [
+        syn::parse_quote!(after(
+            ::bevy_butler::__internal::tick_timer::<ButlerTimerKey>()
+        )),
+        syn::parse_quote!(run_if(::bevy_butler::__internal::timer_finished::<
+            ButlerTimerKey,
+        >())),
+    ];
+    Ok(Some((transforms, init)))
+}
 
-    let mut hash_bytes = "system".to_string();
-    hash_bytes += &plugin.to_token_stream().to_string();
-    hash_bytes += &schedule.to_token_stream().to_string();
-    hash_bytes += &sys_expr.to_token_stream().to_string();
-    #[allow(unused_variables)] // It's actually used
-    let static_ident = format_ident!("_butler_system_{}", sha256::digest(hash_bytes));
+pub(crate) fn with_transforms(system: &Expr, transforms: &TransformList) -> Expr {
+    let transforms = &transforms.0;
+    syn::parse_quote! {
+        #system #(. #transforms)*
+    }
+}
 
-    let register_block = butler_plugin_entry_block(
-        &static_ident,
-        plugin,
+/// Generates the statements adding `system` to each of `schedules`.
+///
+/// If the entry has an `error_handler`, or its plugin does, the system is wrapped so its errors
+/// are passed to the handler, before applying the transforms.
+pub(crate) fn add_systems_stmts(
+    plugin: &Path,
+    ident: &Ident,
+    system: &Expr,
+    transforms: &TransformList,
+    schedules: &[Expr],
+    error_handler: Option<&Expr>,
+) -> TokenStream2 {
+    let routed = with_transforms(
         &syn::parse_quote! {
-            |app| { app.add_systems( #schedule, #sys_expr ); }
+            ::bevy_butler::__internal::route_errors(
+                #system,
+                error_handler,
+                ::core::stringify!(#plugin),
+                ::bevy_butler::registry::ButlerEntryInfo {
+                    kind: "system",
+                    name: ::core::stringify!(#ident),
+                    file: ::core::file!(),
+                    line: ::core::line!(),
+                },
+                false,
+            )
         },
+        transforms,
     );
 
+    match error_handler {
+        Some(error_handler) => quote! {
+            let error_handler: ::bevy_butler::__internal::ButlerErrorHandler = #error_handler;
+            #( app.add_systems( #schedules, #routed ); )*
+        },
+        None => {
+            let plain = with_transforms(system, transforms);
+            quote! {
+                match <#plugin as ::bevy_butler::__internal::ButlerPlugin>::ERROR_HANDLER {
+                    ::core::option::Option::Some(error_handler) => {
+                        #( app.add_systems( #schedules, #routed ); )*
+                    }
+                    ::core::option::Option::None => {
+                        #( app.add_systems( #schedules, #plain ); )*
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Generates the registry entry adding `input` as a system.
+///
+/// `owner` is the type `input` is an associated function of, if any.
+pub(crate) fn system_entry(
+    attr: &SystemAttr,
+    input: &Item,
+    owner: Option<&Path>,
+) -> syn::Result<TokenStream2> {
+    let sys_ident = get_fn_ident(input)?;
+
+    let plugin = &attr.plugin;
+    let schedule = &attr.schedule;
+
+    let function = system_path(owner, sys_ident, attr.generics.as_ref());
+    let system = if attr.is_async.is_set() {
+        async_system(attr, input, owner, sys_ident)?
+    } else {
+        if let Item::Fn(func) = input {
+            if let Some(asyncness) = func.sig.asyncness {
+                return Err(Error::new_spanned(
+                    asyncness,
+                    "`async fn` systems must be added with `#[add_system(async)]`",
+                ));
+            }
+        }
+        pipe_into(function.clone(), attr.pipe_in.as_ref())
+    };
+    let system = with_outputs(system, attr.map.as_ref(), attr.pipe_out.as_ref());
+    let mut transforms = attr.transforms.clone();
+    let timer = timer_transforms(attr, &function)?.map(|(timer_transforms, init)| {
+        transforms.0.extend(timer_transforms);
+        init
+    });
+    let sys_expr = with_transforms(&system, &transforms);
+    let stmts = add_systems_stmts(
+        plugin,
+        sys_ident,
+        &system,
+        &transforms,
+        std::slice::from_ref(schedule),
+        attr.error_handler.as_ref(),
+    );
+    let stmts = quote! {
+        #timer
+        #stmts
+    };
+    // The completed futures of every async system are applied by a single system
+    let stmts = if attr.is_async.is_set() {
+        quote! {
+            if ::bevy_butler::__internal::init_async_polling(app.world_mut()) {
+                app.add_systems(
+                    ::bevy_butler::__internal::bevy_app::PreUpdate,
+                    ::bevy_butler::__internal::poll_async_tasks,
+                );
+            }
+            #stmts
+        }
+    } else {
+        stmts
+    };
+
+    let register_block = ButlerEntry::new("system", plugin, &sys_expr)
+        .hash(schedule)
+        .hash(&attr.error_handler)
+        .schedule(schedule)
+        .phase(attr.phase)
+        .sub_app(attr.sub_app.as_ref())
+        .generate(&syn::parse_quote! {
+            |app| { #stmts }
+        });
+
+    Ok(register_block)
+}
+
+pub(crate) fn macro_impl(attr: TokenStream1, item: TokenStream1) -> syn::Result<TokenStream2> {
+    let attr: SystemAttr = deluxe::parse(attr)?;
+    let input: Item = syn::parse(item)?;
+
+    let register_block = system_entry(&attr, &input, None)?;
+
     Ok(quote! {
         #input
 
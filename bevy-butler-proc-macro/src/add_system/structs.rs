@@ -1,7 +1,7 @@
 use std::borrow::Borrow;
 
-use deluxe::{ParseMetaItem, ParseMetaRest};
-use deluxe_core::parse_helpers::skip_meta_item;
+use deluxe::{Flag, ParseMetaItem, ParseMetaRest};
+use deluxe_core::parse_helpers::{parse_any_path, skip_meta_item};
 use quote::quote;
 use syn::parse::discouraged::AnyDelimiter;
 use syn::parse::{Parse, ParseBuffer};
@@ -9,6 +9,8 @@ use syn::punctuated::Punctuated;
 use syn::Expr;
 use syn::{AngleBracketedGenericArguments, ExprCall, Path, Token};
 
+use crate::utils::ButlerPhase;
+
 #[derive(Clone)]
 pub(crate) struct TransformList(pub Vec<ExprCall>);
 
@@ -31,7 +33,7 @@ impl ParseMetaRest for TransformList {
         for input in inputs.iter() {
             let input = input.borrow();
             while !input.is_empty() {
-                let path = Path::parse(input)?;
+                let path = parse_any_path(input)?;
                 if path
                     .get_ident()
                     .is_some_and(|i| exclude.contains(&i.to_string().as_str()))
@@ -42,7 +44,7 @@ impl ParseMetaRest for TransformList {
                 }
 
                 // Style 1: Path - transform
-                if input.peek(Token![,]) {
+                if input.peek(Token![,]) || input.is_empty() {
                     ret.push(syn::parse2(quote!(#path () ))?);
                     parse_end_comma_or_eof(input)?;
                     continue;
@@ -76,6 +78,17 @@ pub(crate) struct SystemAttr {
     pub schedule: Expr,
     pub generics: Option<AngleBracketedGenericArguments>,
     pub pipe_in: Option<Vec<Expr>>,
+    pub pipe_out: Option<Vec<Expr>>,
+    pub map: Option<Expr>,
+    pub every: Option<Expr>,
+    pub every_fixed_ticks: Option<Expr>,
+    pub initial_delay: Option<Expr>,
+    #[deluxe(rename = async)]
+    pub is_async: Flag,
+    pub error_handler: Option<Expr>,
+    pub sub_app: Option<Expr>,
+    #[deluxe(default)]
+    pub phase: ButlerPhase,
     #[deluxe(rest)]
     pub transforms: TransformList,
 }
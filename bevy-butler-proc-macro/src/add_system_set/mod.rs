@@ -0,0 +1,95 @@
+use proc_macro::TokenStream as TokenStream1;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use structs::{SystemSetAttr, SystemSetVariantAttr};
+use syn::{Error, Expr, Fields, Item};
+
+use crate::utils::ButlerEntry;
+
+pub(crate) mod structs;
+
+pub(crate) fn macro_impl(attr: TokenStream1, body: TokenStream1) -> syn::Result<TokenStream2> {
+    let attr: SystemSetAttr = deluxe::parse(attr)?;
+    let mut item: Item = syn::parse(body)?;
+
+    // The set, or sets, configured by the attribute, and any per-variant configuration
+    let (ident, sets, variant_configs) = match &mut item {
+        Item::Struct(i_struct) if matches!(i_struct.fields, Fields::Unit) => {
+            let ident = &i_struct.ident;
+            let set: Expr = syn::parse_quote!(#ident);
+            (ident.clone(), vec![set], Vec::new())
+        }
+        Item::Enum(i_enum) => {
+            if i_enum.variants.is_empty() {
+                return Err(Error::new_spanned(
+                    &i_enum.ident,
+                    "Expected at least one variant",
+                ));
+            }
+
+            let ident = i_enum.ident.clone();
+            let mut sets = Vec::new();
+            let mut variant_configs = Vec::new();
+            for variant in i_enum.variants.iter_mut() {
+                if !matches!(variant.fields, Fields::Unit) {
+                    return Err(Error::new_spanned(
+                        &variant.fields,
+                        "`add_system_set` only supports unit variants",
+                    ));
+                }
+
+                let variant_ident = &variant.ident;
+                let set: Expr = syn::parse_quote!(#ident::#variant_ident);
+
+                // Take the `#[butler]` helper attributes off the variant, so they don't reach the compiler
+                let mut butler_attrs = Vec::new();
+                variant.attrs.retain(|attr| {
+                    if attr.path().is_ident("butler") {
+                        butler_attrs.push(attr.clone());
+                        false
+                    } else {
+                        true
+                    }
+                });
+
+                for butler_attr in butler_attrs {
+                    let variant_attr: SystemSetVariantAttr = deluxe::parse2(
+                        butler_attr.meta.require_list()?.tokens.clone(),
+                    )?;
+                    let transforms = &variant_attr.transforms.0;
+                    variant_configs.push(quote!(#set #(. #transforms)*));
+                }
+
+                sets.push(set);
+            }
+            (ident, sets, variant_configs)
+        }
+        item => {
+            return Err(Error::new_spanned(
+                item,
+                "Expected a unit `struct` or an `enum`",
+            ))
+        }
+    };
+
+    let schedule = &attr.schedule;
+    let transforms = &attr.transforms.0;
+
+    let register_block = ButlerEntry::new("system_set", &attr.plugin, &ident)
+        .hash(schedule)
+        .schedule(schedule)
+        .phase(attr.phase)
+        .sub_app(attr.sub_app.as_ref())
+        .generate(&syn::parse_quote! {
+            |app| {
+                app.configure_sets( #schedule, ( #(#sets,)* ) #(. #transforms)* );
+                #( app.configure_sets( #schedule, #variant_configs ); )*
+            }
+        });
+
+    Ok(quote! {
+        #item
+
+        #register_block
+    })
+}
@@ -0,0 +1,23 @@
+use deluxe::ParseMetaItem;
+use syn::{Expr, Path};
+
+use crate::add_system::structs::TransformList;
+use crate::utils::ButlerPhase;
+
+#[derive(ParseMetaItem)]
+pub(crate) struct SystemSetAttr {
+    pub plugin: Path,
+    pub schedule: Expr,
+    pub sub_app: Option<Expr>,
+    #[deluxe(default)]
+    pub phase: ButlerPhase,
+    #[deluxe(rest)]
+    pub transforms: TransformList,
+}
+
+/// A `#[butler(...)]` attribute on an enum variant
+#[derive(ParseMetaItem)]
+pub(crate) struct SystemSetVariantAttr {
+    #[deluxe(rest)]
+    pub transforms: TransformList,
+}
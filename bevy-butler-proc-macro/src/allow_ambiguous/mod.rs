@@ -0,0 +1,48 @@
+use proc_macro::TokenStream as TokenStream1;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use structs::AllowAmbiguousAttr;
+use syn::Item;
+
+use crate::utils::{get_struct_or_enum_ident, ButlerEntry};
+
+pub(crate) mod structs;
+
+/// What kind of data `#[allow_ambiguous_*]` ignores ambiguities on
+#[derive(Clone, Copy)]
+pub(crate) enum AmbiguousKind {
+    Resource,
+    Component,
+}
+
+pub(crate) fn macro_impl(
+    attr: TokenStream1,
+    body: TokenStream1,
+    kind: AmbiguousKind,
+) -> syn::Result<TokenStream2> {
+    let attr: AllowAmbiguousAttr = deluxe::parse(attr)?;
+    let item: Item = syn::parse(body)?;
+    let ident = get_struct_or_enum_ident(&item)?;
+    let generics = &attr.generics;
+
+    let (entry_kind, method) = match kind {
+        AmbiguousKind::Resource => ("allow_ambiguous_resource", quote!(allow_ambiguous_resource)),
+        AmbiguousKind::Component => (
+            "allow_ambiguous_component",
+            quote!(allow_ambiguous_component),
+        ),
+    };
+
+    let register_block = ButlerEntry::new(entry_kind, &attr.plugin, quote!(#ident #generics))
+        .phase(attr.phase)
+        .sub_app(attr.sub_app.as_ref())
+        .generate(&syn::parse_quote! {
+            |app| { app.#method::<#ident #generics>(); }
+        });
+
+    Ok(quote! {
+        #item
+
+        #register_block
+    })
+}
@@ -0,0 +1,13 @@
+use deluxe::ParseMetaItem;
+use syn::{AngleBracketedGenericArguments, Expr, Path};
+
+use crate::utils::ButlerPhase;
+
+#[derive(ParseMetaItem)]
+pub(crate) struct AllowAmbiguousAttr {
+    pub plugin: Path,
+    pub generics: Option<AngleBracketedGenericArguments>,
+    pub sub_app: Option<Expr>,
+    #[deluxe(default)]
+    pub phase: ButlerPhase,
+}
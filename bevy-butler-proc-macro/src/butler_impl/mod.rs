@@ -0,0 +1,94 @@
+use proc_macro::TokenStream as TokenStream1;
+use proc_macro2::{Group, TokenStream as TokenStream2, TokenTree};
+use quote::{quote, ToTokens};
+use structs::ButlerImplAttr;
+use syn::{Attribute, Error, ImplItem, Item, ItemFn, ItemImpl, Path, Type, TypePath};
+
+use crate::add_system::structs::SystemAttr;
+use crate::add_system::system_entry;
+
+pub(crate) mod structs;
+
+fn is_add_system(attr: &Attribute) -> bool {
+    attr.path()
+        .segments
+        .last()
+        .is_some_and(|segment| segment.ident == "add_system")
+}
+
+/// Replaces every `Self` in `tokens` with `self_ty`, as the entries are generated outside the impl
+fn replace_self(tokens: TokenStream2, self_ty: &Path) -> TokenStream2 {
+    tokens
+        .into_iter()
+        .map(|tree| match tree {
+            TokenTree::Ident(ident) if ident == "Self" => self_ty.to_token_stream(),
+            TokenTree::Group(group) => {
+                let mut replaced =
+                    Group::new(group.delimiter(), replace_self(group.stream(), self_ty));
+                replaced.set_span(group.span());
+                TokenTree::Group(replaced).into_token_stream()
+            }
+            tree => tree.into_token_stream(),
+        })
+        .collect()
+}
+
+pub(crate) fn macro_impl(attr: TokenStream1, body: TokenStream1) -> syn::Result<TokenStream2> {
+    let ButlerImplAttr {} = deluxe::parse(attr)?;
+    let mut item: ItemImpl = syn::parse(body)?;
+
+    if let Some((_, path, _)) = &item.trait_ {
+        return Err(Error::new_spanned(
+            path,
+            "`#[butler_impl]` can only be used on inherent impls",
+        ));
+    }
+    if !item.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &item.generics,
+            "`#[butler_impl]` can't be used on generic impls",
+        ));
+    }
+    let Type::Path(TypePath {
+        qself: None,
+        path: self_ty,
+    }) = &*item.self_ty
+    else {
+        return Err(Error::new_spanned(&item.self_ty, "Expected a type path"));
+    };
+    let self_ty = self_ty.clone();
+
+    let mut entries = Vec::new();
+    for impl_item in &mut item.items {
+        let ImplItem::Fn(func) = impl_item else {
+            continue;
+        };
+        let (systems, attrs): (Vec<Attribute>, Vec<Attribute>) =
+            func.attrs.drain(..).partition(is_add_system);
+        func.attrs = attrs;
+        if systems.is_empty() {
+            continue;
+        }
+        if let Some(receiver) = func.sig.receiver() {
+            return Err(Error::new_spanned(receiver, "Systems can't take `self`"));
+        }
+
+        let input = Item::Fn(ItemFn {
+            attrs: Vec::new(),
+            vis: func.vis.clone(),
+            sig: func.sig.clone(),
+            block: Box::new(func.block.clone()),
+        });
+        for system in systems {
+            let tokens = replace_self(system.meta.require_list()?.tokens.clone(), &self_ty);
+            let attr: SystemAttr = deluxe::parse2(tokens)?;
+            entries.push(system_entry(&attr, &input, Some(&self_ty))?);
+        }
+    }
+
+    Ok(quote! {
+        #item
+
+        #(#entries)*
+    })
+}
@@ -0,0 +1,4 @@
+use deluxe::ParseMetaItem;
+
+#[derive(ParseMetaItem)]
+pub(crate) struct ButlerImplAttr {}
@@ -0,0 +1,34 @@
+use proc_macro::TokenStream as TokenStream1;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use structs::ButlerPhaseFnAttr;
+use syn::Item;
+
+use crate::utils::{get_fn_ident, ButlerEntry, ButlerPhase};
+
+pub(crate) mod structs;
+
+pub(crate) fn macro_impl(
+    attr: TokenStream1,
+    body: TokenStream1,
+    phase: ButlerPhase,
+) -> syn::Result<TokenStream2> {
+    let attr: ButlerPhaseFnAttr = deluxe::parse(attr)?;
+    let item: Item = syn::parse(body)?;
+    let ident = get_fn_ident(&item)?;
+
+    let plugin = &attr.plugin;
+
+    let kind = format_ident!("{}_fn", phase.fn_name());
+    let register_block = ButlerEntry::new(&kind.to_string(), plugin, ident)
+        .phase(phase)
+        .generate(&syn::parse_quote! {
+            |app| { #ident(app); }
+        });
+
+    Ok(quote! {
+        #item
+
+        #register_block
+    })
+}
@@ -0,0 +1,7 @@
+use deluxe::ParseMetaItem;
+use syn::Path;
+
+#[derive(ParseMetaItem)]
+pub(crate) struct ButlerPhaseFnAttr {
+    pub plugin: Path,
+}
@@ -1,12 +1,16 @@
 use proc_macro::TokenStream as TokenStream1;
-use proc_macro2::TokenStream as TokenStream2;
+use proc_macro2::{Span, TokenStream as TokenStream2};
 use quote::{format_ident, quote};
+use structs::{ButlerPluginAttr, RegisterPosition};
 use syn::{
-    parse_quote, Error, FnArg, Ident, ImplItem, Item, ItemEnum, ItemImpl, ItemStruct, Pat, TypePath,
+    parse_quote, Error, Expr, FnArg, Ident, ImplItem, Item, ItemEnum, ItemImpl, ItemStruct, Pat,
+    Stmt, TypePath,
 };
 
-#[derive(deluxe::ParseMetaItem)]
-pub struct ButlerPluginAttr;
+use crate::configure_schedule::edit_build_settings;
+use crate::utils::ButlerPhase;
+
+pub(crate) mod structs;
 
 pub(crate) fn macro_impl(attr: TokenStream1, item: TokenStream1) -> syn::Result<TokenStream2> {
     let attr: ButlerPluginAttr = deluxe::parse(attr)?;
@@ -22,17 +26,34 @@ pub(crate) fn macro_impl(attr: TokenStream1, item: TokenStream1) -> syn::Result<
     }
 }
 
-fn register_butler_plugin_stmts(plugin: &TypePath) -> TokenStream2 {
+fn register_butler_plugin_stmts(attr: &ButlerPluginAttr, plugin: &TypePath) -> TokenStream2 {
+    let configure_schedule = edit_build_settings(&attr.build_settings).map(|edit| {
+        quote! {
+            const CONFIGURE_SCHEDULE: ::core::option::Option<fn(&mut ::bevy_butler::__internal::bevy_ecs::schedule::Schedule)> =
+                ::core::option::Option::Some(|schedule| { #edit });
+        }
+    });
+
+    let error_handler = attr.error_handler.as_ref().map(|error_handler| {
+        quote! {
+            const ERROR_HANDLER: ::core::option::Option<::bevy_butler::__internal::ButlerErrorHandler> =
+                ::core::option::Option::Some(#error_handler);
+        }
+    });
+
     quote! {
-        impl #plugin {
-            pub(crate) fn _butler_plugin_sealed_marker() -> ::std::any::TypeId {
+        impl ::bevy_butler::registry::ButlerPluginMarker for #plugin {
+            fn butler_plugin_marker() -> ::std::any::TypeId {
                 struct SealedMarker;
 
                 ::std::any::TypeId::of::<SealedMarker>()
             }
         }
 
-        impl ::bevy_butler::__internal::ButlerPlugin for #plugin {}
+        impl ::bevy_butler::__internal::ButlerPlugin for #plugin {
+            #error_handler
+            #configure_schedule
+        }
     }
 }
 
@@ -57,15 +78,30 @@ pub(crate) fn enum_impl(_attr: ButlerPluginAttr, item: ItemEnum) -> syn::Result<
 }
 
 pub(crate) fn impl_plugin_block(
-    _attr: ButlerPluginAttr,
+    attr: ButlerPluginAttr,
     ident: &Ident,
 ) -> syn::Result<TokenStream2> {
-    let register_block = register_butler_plugin_stmts(&syn::parse2(quote!(#ident))?);
+    if attr.register.is_some() {
+        return Err(Error::new(
+            Span::call_site(),
+            "`register` is only supported on `impl Plugin` blocks",
+        ));
+    }
+
+    let register_block = register_butler_plugin_stmts(&attr, &syn::parse2(quote!(#ident))?);
 
     Ok(quote! {
         impl ::bevy_butler::__internal::bevy_app::Plugin for #ident {
             fn build(&self, app: &mut ::bevy_butler::__internal::bevy_app::App) {
-                <Self as ::bevy_butler::__internal::ButlerPlugin>::register_butler_systems(app, Self::_butler_plugin_sealed_marker());
+                <Self as ::bevy_butler::__internal::ButlerPlugin>::register_butler_systems(app, <Self as ::bevy_butler::registry::ButlerPluginMarker>::butler_plugin_marker());
+            }
+
+            fn finish(&self, app: &mut ::bevy_butler::__internal::bevy_app::App) {
+                <Self as ::bevy_butler::__internal::ButlerPlugin>::run_butler_phase(app, <Self as ::bevy_butler::registry::ButlerPluginMarker>::butler_plugin_marker(), ::bevy_butler::registry::ButlerPhase::Finish);
+            }
+
+            fn cleanup(&self, app: &mut ::bevy_butler::__internal::bevy_app::App) {
+                <Self as ::bevy_butler::__internal::ButlerPlugin>::run_butler_phase(app, <Self as ::bevy_butler::registry::ButlerPluginMarker>::butler_plugin_marker(), ::bevy_butler::registry::ButlerPhase::Cleanup);
             }
         }
 
@@ -73,55 +109,137 @@ pub(crate) fn impl_plugin_block(
     })
 }
 
-pub(crate) fn impl_impl(_attr: ButlerPluginAttr, mut body: ItemImpl) -> syn::Result<TokenStream2> {
-    let register_block = |app_ident: &Ident| {
+/// Returns the app expression if `stmt` is a `butler_register!(app);` marker
+fn parse_register_marker(stmt: &Stmt) -> syn::Result<Option<Expr>> {
+    let Stmt::Macro(stmt) = stmt else {
+        return Ok(None);
+    };
+    let is_marker = stmt
+        .mac
+        .path
+        .segments
+        .last()
+        .is_some_and(|seg| seg.ident == "butler_register");
+    if !is_marker {
+        return Ok(None);
+    }
+    stmt.mac.parse_body().map(Some)
+}
+
+/// Inserts the registration step for `phase` into the matching `Plugin` method,
+/// generating the method if the user didn't write one.
+///
+/// Returns whether the method was user-defined.
+fn inject_phase(
+    body: &mut ItemImpl,
+    phase: ButlerPhase,
+    position: Option<RegisterPosition>,
+) -> syn::Result<bool> {
+    let register_block = |app: &Expr| {
         syn::parse2(quote!(
-            <Self as ::bevy_butler::__internal::ButlerPlugin>::register_butler_systems(#app_ident, Self::_butler_plugin_sealed_marker());
+            <Self as ::bevy_butler::__internal::ButlerPlugin>::run_butler_phase(#app, <Self as ::bevy_butler::registry::ButlerPluginMarker>::butler_plugin_marker(), #phase);
         ))
     };
 
-    let build_index = body.items.iter().position(|i| {
+    let fn_index = body.items.iter().position(|i| {
         if let ImplItem::Fn(item) = i {
-            return item.sig.ident == "build";
+            return item.sig.ident == phase.fn_name();
         }
         false
     });
 
-    if let Some(build_index) = build_index {
-        // Insert butler statement into build func
-        let ImplItem::Fn(build) = &mut body.items[build_index] else {
-            unreachable!();
-        };
-
-        // Figure out the identifier of the `&mut App` argument
-        let app_ident = build
-            .sig
-            .inputs
-            .get(1)
-            .ok_or(Error::new_spanned(&build.sig, "Missing `app` argument?"))?;
-        let app_ident = match app_ident {
-            FnArg::Typed(ident) => match &*ident.pat {
-                Pat::Ident(ident) => &ident.ident,
-                other => return Err(Error::new_spanned(other, "Expected `app: &mut App`")),
-            },
-            FnArg::Receiver(r) => return Err(Error::new_spanned(r, "Receiver arg in arg 1????")),
-        };
-
-        // Insert our registration step into the beginning
-        build.block.stmts.insert(0, register_block(app_ident)?);
-    } else {
-        // No build statement, insert it ourselves
-        let register = register_block(&format_ident!("app"))?;
+    let Some(fn_index) = fn_index else {
+        // No matching func, insert it ourselves
+        let fn_ident = format_ident!("{}", phase.fn_name());
+        let register = register_block(&parse_quote!(app))?;
         body.items.push(parse_quote! {
-            fn build(&self, app: &mut ::bevy_butler::__internal::bevy_app::App) {
+            fn #fn_ident(&self, app: &mut ::bevy_butler::__internal::bevy_app::App) {
                 #register
             }
         });
+        return Ok(false);
+    };
+
+    // Insert butler statement into the existing func
+    let ImplItem::Fn(func) = &mut body.items[fn_index] else {
+        unreachable!();
+    };
+
+    // Look for a user-placed `butler_register!(app);`
+    let mut marker = None;
+    for (index, stmt) in func.block.stmts.iter().enumerate() {
+        if let Some(app) = parse_register_marker(stmt)? {
+            if marker.is_some() {
+                return Err(Error::new_spanned(
+                    stmt,
+                    "`butler_register!` can only be used once per function",
+                ));
+            }
+            if position.is_some() {
+                return Err(Error::new_spanned(
+                    stmt,
+                    "`butler_register!` can't be combined with `#[butler_plugin(register = ...)]`",
+                ));
+            }
+            marker = Some((index, app));
+        }
+    }
+
+    if let Some((index, app)) = marker {
+        func.block.stmts[index] = register_block(&app)?;
+        return Ok(true);
+    }
+
+    // Figure out the identifier of the `&mut App` argument
+    let app_ident = func
+        .sig
+        .inputs
+        .get(1)
+        .ok_or(Error::new_spanned(&func.sig, "Missing `app` argument?"))?;
+    let app_ident = match app_ident {
+        FnArg::Typed(ident) => match &*ident.pat {
+            Pat::Ident(ident) => &ident.ident,
+            other => return Err(Error::new_spanned(other, "Expected `app: &mut App`")),
+        },
+        FnArg::Receiver(r) => return Err(Error::new_spanned(r, "Receiver arg in arg 1????")),
+    };
+    let register = register_block(&parse_quote!(#app_ident))?;
+
+    match position.unwrap_or(RegisterPosition::Start) {
+        // Insert our registration step into the beginning
+        RegisterPosition::Start => func.block.stmts.insert(0, register),
+        RegisterPosition::End => {
+            // A trailing expression needs a semicolon before anything can follow it
+            if let Some(Stmt::Expr(_, semi @ None)) = func.block.stmts.last_mut() {
+                *semi = Some(Default::default());
+            }
+            func.block.stmts.push(register);
+        }
+    }
+
+    Ok(true)
+}
+
+pub(crate) fn impl_impl(attr: ButlerPluginAttr, mut body: ItemImpl) -> syn::Result<TokenStream2> {
+    let mut user_defined = false;
+    for phase in [
+        ButlerPhase::Build,
+        ButlerPhase::Finish,
+        ButlerPhase::Cleanup,
+    ] {
+        user_defined |= inject_phase(&mut body, phase, attr.register)?;
+    }
+
+    if attr.register.is_some() && !user_defined {
+        return Err(Error::new(
+            Span::call_site(),
+            "`register` has no effect without a `build`, `finish` or `cleanup` function",
+        ));
     }
 
     let plugin = &body.self_ty;
 
-    let register_block = register_butler_plugin_stmts(&syn::parse2(quote!(#plugin))?);
+    let register_block = register_butler_plugin_stmts(&attr, &syn::parse2(quote!(#plugin))?);
 
     Ok(quote! {
         #body
@@ -0,0 +1,31 @@
+use deluxe::{ParseMetaItem, ParseMode};
+use syn::parse::ParseStream;
+use syn::{Expr, Ident};
+
+use crate::configure_schedule::structs::BuildSettingsAttr;
+
+/// Where the registration step is inserted into a user-defined `Plugin` method.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum RegisterPosition {
+    Start,
+    End,
+}
+
+impl ParseMetaItem for RegisterPosition {
+    fn parse_meta_item(input: ParseStream, _mode: ParseMode) -> deluxe::Result<Self> {
+        let ident: Ident = input.parse()?;
+        match ident.to_string().as_str() {
+            "start" => Ok(Self::Start),
+            "end" => Ok(Self::End),
+            _ => Err(syn::Error::new_spanned(ident, "Expected `start` or `end`")),
+        }
+    }
+}
+
+#[derive(ParseMetaItem)]
+pub(crate) struct ButlerPluginAttr {
+    pub register: Option<RegisterPosition>,
+    pub error_handler: Option<Expr>,
+    #[deluxe(flatten)]
+    pub build_settings: BuildSettingsAttr,
+}
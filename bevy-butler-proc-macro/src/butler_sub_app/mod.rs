@@ -0,0 +1,48 @@
+use proc_macro::TokenStream as TokenStream1;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use structs::ButlerSubAppAttr;
+use syn::{parse_quote, Item};
+
+use crate::utils::{default_label, ButlerEntry};
+
+pub(crate) mod structs;
+
+pub(crate) fn macro_impl(attr: TokenStream1, body: TokenStream1) -> syn::Result<TokenStream2> {
+    let mut attr: ButlerSubAppAttr = deluxe::parse(attr)?;
+    let item: Item = syn::parse(body)?;
+
+    if attr.label.is_none() {
+        attr.label = Some(default_label(&item)?);
+    }
+
+    let label = &attr.label;
+    let extract = attr.extract.as_ref().map(|extract| {
+        quote! { sub_app.set_extract(#extract); }
+    });
+    let update_schedule = attr.update_schedule.as_ref().map(|schedule| {
+        quote! {
+            sub_app.update_schedule = Some(
+                ::bevy_butler::__internal::bevy_ecs::schedule::ScheduleLabel::intern(&#schedule)
+            );
+        }
+    });
+
+    let register_block = ButlerEntry::new("sub_app", &attr.plugin, label)
+        .phase(attr.phase)
+        .inserts_sub_app()
+        .generate(&parse_quote! {
+            |app| {
+                #[allow(unused_variables)]
+                let sub_app = ::bevy_butler::__internal::init_sub_app(app, #label);
+                #extract
+                #update_schedule
+            }
+        });
+
+    Ok(quote! {
+        #item
+
+        #register_block
+    })
+}
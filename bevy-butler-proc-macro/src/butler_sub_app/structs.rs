@@ -0,0 +1,14 @@
+use deluxe::ParseMetaItem;
+use syn::{Expr, Path};
+
+use crate::utils::ButlerPhase;
+
+#[derive(ParseMetaItem)]
+pub(crate) struct ButlerSubAppAttr {
+    pub plugin: Path,
+    pub label: Option<Expr>,
+    pub extract: Option<Expr>,
+    pub update_schedule: Option<Expr>,
+    #[deluxe(default)]
+    pub phase: ButlerPhase,
+}
@@ -0,0 +1,83 @@
+use proc_macro::TokenStream as TokenStream1;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use structs::ComponentHookAttr;
+use syn::{ExprClosure, Item};
+
+use crate::utils::{get_fn_ident, ButlerEntry};
+
+pub(crate) mod structs;
+
+#[derive(Clone, Copy)]
+pub(crate) enum HookKind {
+    Add,
+    Insert,
+    Replace,
+    Remove,
+    Despawn,
+}
+
+impl HookKind {
+    fn name(self) -> &'static str {
+        match self {
+            HookKind::Add => "on_add",
+            HookKind::Insert => "on_insert",
+            HookKind::Replace => "on_replace",
+            HookKind::Remove => "on_remove",
+            HookKind::Despawn => "on_despawn",
+        }
+    }
+
+    fn variant(self) -> proc_macro2::Ident {
+        match self {
+            HookKind::Add => format_ident!("Add"),
+            HookKind::Insert => format_ident!("Insert"),
+            HookKind::Replace => format_ident!("Replace"),
+            HookKind::Remove => format_ident!("Remove"),
+            HookKind::Despawn => format_ident!("Despawn"),
+        }
+    }
+}
+
+pub(crate) fn macro_impl(
+    attr: TokenStream1,
+    body: TokenStream1,
+    kind: HookKind,
+) -> syn::Result<TokenStream2> {
+    let attr: ComponentHookAttr = deluxe::parse(attr)?;
+    let item = syn::parse::<Item>(body)?;
+    let ident = get_fn_ident(&item)?;
+
+    let plugin = &attr.plugin;
+    let component = &attr.component;
+    let kind_name = kind.name();
+    let variant = kind.variant();
+
+    let entry_expr: ExprClosure = syn::parse_quote! {
+        |app| {
+            ::bevy_butler::__internal::register_component_hook::<#component>(
+                app.world_mut(),
+                ::bevy_butler::__internal::ButlerHookKind::#variant,
+                #ident,
+                ::bevy_butler::registry::ButlerEntryInfo {
+                    kind: #kind_name,
+                    name: ::core::stringify!(#ident),
+                    file: ::core::file!(),
+                    line: ::core::line!(),
+                },
+            );
+        }
+    };
+
+    let register_block = ButlerEntry::new(kind_name, plugin, ident)
+        .hash(component)
+        .phase(attr.phase)
+        .sub_app(attr.sub_app.as_ref())
+        .generate(&entry_expr);
+
+    Ok(quote! {
+        #item
+
+        #register_block
+    })
+}
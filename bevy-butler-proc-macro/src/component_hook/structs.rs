@@ -0,0 +1,13 @@
+use deluxe::ParseMetaItem;
+use syn::{Expr, Path, Type};
+
+use crate::utils::ButlerPhase;
+
+#[derive(ParseMetaItem)]
+pub(crate) struct ComponentHookAttr {
+    pub plugin: Path,
+    pub component: Type,
+    pub sub_app: Option<Expr>,
+    #[deluxe(default)]
+    pub phase: ButlerPhase,
+}
@@ -0,0 +1,70 @@
+use proc_macro::TokenStream as TokenStream1;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
+use structs::{BuildSettingsAttr, ConfigureScheduleAttr};
+use syn::{parse_quote, Error, Item};
+
+use crate::utils::{default_label, ButlerEntry};
+
+pub(crate) mod structs;
+
+/// Generates statements overriding the given settings of a `schedule: &mut Schedule`,
+/// or `None` if no settings were given
+pub(crate) fn edit_build_settings(settings: &BuildSettingsAttr) -> Option<TokenStream2> {
+    let mut assignments = Vec::new();
+    if let Some(level) = &settings.ambiguity_detection {
+        assignments.push(quote!(settings.ambiguity_detection = #level;));
+    }
+    if let Some(level) = &settings.hierarchy_detection {
+        assignments.push(quote!(settings.hierarchy_detection = #level;));
+    }
+    if let Some(value) = &settings.auto_insert_apply_deferred {
+        assignments.push(quote!(settings.auto_insert_apply_deferred = #value;));
+    }
+    if let Some(value) = &settings.use_shortnames {
+        assignments.push(quote!(settings.use_shortnames = #value;));
+    }
+    if let Some(value) = &settings.report_sets {
+        assignments.push(quote!(settings.report_sets = #value;));
+    }
+
+    if assignments.is_empty() {
+        return None;
+    }
+
+    Some(quote! {
+        let mut settings = schedule.get_build_settings();
+        #(#assignments)*
+        schedule.set_build_settings(settings);
+    })
+}
+
+pub(crate) fn macro_impl(attr: TokenStream1, body: TokenStream1) -> syn::Result<TokenStream2> {
+    let mut attr: ConfigureScheduleAttr = deluxe::parse(attr)?;
+    let item: Item = syn::parse(body)?;
+
+    if attr.label.is_none() {
+        attr.label = Some(default_label(&item)?);
+    }
+    let label = &attr.label;
+
+    let edit = edit_build_settings(&attr.settings).ok_or(Error::new(
+        Span::call_site(),
+        "Expected at least one schedule build setting",
+    ))?;
+
+    let register_block = ButlerEntry::new("configure_schedule", &attr.plugin, label)
+        .phase(attr.phase)
+        .sub_app(attr.sub_app.as_ref())
+        .generate(&parse_quote! {
+            |app| {
+                app.edit_schedule(#label, |schedule| { #edit });
+            }
+        });
+
+    Ok(quote! {
+        #item
+
+        #register_block
+    })
+}
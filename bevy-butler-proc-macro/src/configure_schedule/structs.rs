@@ -0,0 +1,61 @@
+use deluxe::{ParseMetaItem, ParseMode};
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::parse::ParseStream;
+use syn::{Expr, Ident, LitBool, Path};
+
+use crate::utils::ButlerPhase;
+
+/// A `LogLevel` of a schedule build setting
+#[derive(Clone, Copy)]
+pub(crate) enum LogLevelArg {
+    Ignore,
+    Warn,
+    Error,
+}
+
+impl ParseMetaItem for LogLevelArg {
+    fn parse_meta_item(input: ParseStream, _mode: ParseMode) -> deluxe::Result<Self> {
+        let ident: Ident = input.parse()?;
+        match ident.to_string().as_str() {
+            "ignore" => Ok(Self::Ignore),
+            "warn" => Ok(Self::Warn),
+            "error" => Ok(Self::Error),
+            _ => Err(syn::Error::new_spanned(
+                ident,
+                "Expected `ignore`, `warn` or `error`",
+            )),
+        }
+    }
+}
+
+impl ToTokens for LogLevelArg {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            Self::Ignore => quote!(::bevy_butler::__internal::bevy_ecs::schedule::LogLevel::Ignore),
+            Self::Warn => quote!(::bevy_butler::__internal::bevy_ecs::schedule::LogLevel::Warn),
+            Self::Error => quote!(::bevy_butler::__internal::bevy_ecs::schedule::LogLevel::Error),
+        });
+    }
+}
+
+/// `ScheduleBuildSettings` fields to override, shared by `#[butler_plugin]` and `#[configure_schedule]`
+#[derive(ParseMetaItem)]
+pub(crate) struct BuildSettingsAttr {
+    pub ambiguity_detection: Option<LogLevelArg>,
+    pub hierarchy_detection: Option<LogLevelArg>,
+    pub auto_insert_apply_deferred: Option<LitBool>,
+    pub use_shortnames: Option<LitBool>,
+    pub report_sets: Option<LitBool>,
+}
+
+#[derive(ParseMetaItem)]
+pub(crate) struct ConfigureScheduleAttr {
+    pub plugin: Path,
+    pub label: Option<Expr>,
+    pub sub_app: Option<Expr>,
+    #[deluxe(default)]
+    pub phase: ButlerPhase,
+    #[deluxe(flatten)]
+    pub settings: BuildSettingsAttr,
+}
@@ -0,0 +1,50 @@
+use proc_macro::TokenStream as TokenStream1;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use structs::IgnoreAmbiguityAttr;
+use syn::{Error, Item};
+
+use crate::utils::{get_fn_ident, get_struct_or_enum_ident, ButlerEntry};
+
+pub(crate) mod structs;
+
+pub(crate) fn macro_impl(attr: TokenStream1, body: TokenStream1) -> syn::Result<TokenStream2> {
+    let attr: IgnoreAmbiguityAttr = deluxe::parse(attr)?;
+    let item: Item = syn::parse(body)?;
+
+    // Systems and system sets can both be used
+    let ident = match &item {
+        Item::Fn(_) => get_fn_ident(&item)?,
+        Item::Struct(_) | Item::Type(_) | Item::Use(_) => get_struct_or_enum_ident(&item)?,
+        // The system set is a variant, not the enum itself
+        Item::Enum(_) => {
+            return Err(Error::new_spanned(
+                item,
+                "Enum system sets can't be annotated, use one of their variants in the `with` of a system or struct set instead",
+            ))
+        }
+        other => {
+            return Err(Error::new_spanned(
+                other,
+                "Expected a system function or a system set",
+            ))
+        }
+    };
+
+    let schedule = &attr.schedule;
+    let with = &attr.with.0;
+
+    let register_block = ButlerEntry::new("ignore_ambiguity", &attr.plugin, ident)
+        .hash(quote!(#schedule #(#with)*))
+        .phase(attr.phase)
+        .sub_app(attr.sub_app.as_ref())
+        .generate(&syn::parse_quote! {
+            |app| { #( app.ignore_ambiguity(#schedule, #ident, #with); )* }
+        });
+
+    Ok(quote! {
+        #item
+
+        #register_block
+    })
+}
@@ -0,0 +1,14 @@
+use deluxe::ParseMetaItem;
+use syn::{Expr, Path};
+
+use crate::utils::{ButlerPhase, ExprList};
+
+#[derive(ParseMetaItem)]
+pub(crate) struct IgnoreAmbiguityAttr {
+    pub plugin: Path,
+    pub schedule: Expr,
+    pub with: ExprList,
+    pub sub_app: Option<Expr>,
+    #[deluxe(default)]
+    pub phase: ButlerPhase,
+}
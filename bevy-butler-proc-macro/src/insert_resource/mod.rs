@@ -1,13 +1,45 @@
 use proc_macro::TokenStream as TokenStream1;
 use proc_macro2::TokenStream as TokenStream2;
-use quote::{format_ident, quote, ToTokens};
+use quote::quote;
 use structs::*;
-use syn::Item;
+use syn::{Expr, ExprClosure, Ident, Item};
 
-use crate::utils::{butler_plugin_entry_block, get_struct_or_enum_ident};
+use crate::utils::{get_struct_or_enum_ident, ButlerEntry};
 
 pub(crate) mod structs;
 
+/// Adds a pair of systems that insert the resource when `state` is entered,
+/// and remove it again when it is exited
+fn scoped_entry_expr(attr: &ResourceAttr, res_ident: &Ident, state: &Expr) -> ExprClosure {
+    let generics = &attr.generics;
+    let (insert, remove): (TokenStream2, TokenStream2) = match (&attr.init, attr.non_send.is_set())
+    {
+        (Some(expr), false) => (
+            quote!(|mut commands: ::bevy_butler::__internal::bevy_ecs::system::Commands| { commands.insert_resource(#expr); }),
+            quote!(|mut commands: ::bevy_butler::__internal::bevy_ecs::system::Commands| { commands.remove_resource::<#res_ident #generics>(); }),
+        ),
+        (None, false) => (
+            quote!(|mut commands: ::bevy_butler::__internal::bevy_ecs::system::Commands| { commands.init_resource::<#res_ident #generics>(); }),
+            quote!(|mut commands: ::bevy_butler::__internal::bevy_ecs::system::Commands| { commands.remove_resource::<#res_ident #generics>(); }),
+        ),
+        (Some(expr), true) => (
+            quote!(|world: &mut ::bevy_butler::__internal::bevy_ecs::world::World| { world.insert_non_send_resource(#expr); }),
+            quote!(|world: &mut ::bevy_butler::__internal::bevy_ecs::world::World| { world.remove_non_send_resource::<#res_ident #generics>(); }),
+        ),
+        (None, true) => (
+            quote!(|world: &mut ::bevy_butler::__internal::bevy_ecs::world::World| { world.init_non_send_resource::<#res_ident #generics>(); }),
+            quote!(|world: &mut ::bevy_butler::__internal::bevy_ecs::world::World| { world.remove_non_send_resource::<#res_ident #generics>(); }),
+        ),
+    };
+
+    syn::parse_quote! {
+        |app| {
+            app.add_systems(::bevy_butler::__internal::bevy_state::prelude::OnEnter(#state), #insert);
+            app.add_systems(::bevy_butler::__internal::bevy_state::prelude::OnExit(#state), #remove);
+        }
+    }
+}
+
 pub(crate) fn macro_impl(attr: TokenStream1, body: TokenStream1) -> syn::Result<TokenStream2> {
     let attr: ResourceAttr = deluxe::parse(attr)?;
     let item = syn::parse::<Item>(body)?;
@@ -16,27 +48,29 @@ pub(crate) fn macro_impl(attr: TokenStream1, body: TokenStream1) -> syn::Result<
     let plugin = &attr.plugin;
     let generics = &attr.generics;
 
-    let mut hash_bytes = res_ident.to_string();
-    hash_bytes += &plugin.to_token_stream().to_string();
-    hash_bytes += &generics.to_token_stream().to_string();
-    let static_ident = format_ident!("_butler_resource_{}", sha256::digest(hash_bytes));
-
-    let entry_expr = match (&attr.init, attr.non_send.is_set()) {
-        (Some(expr), false) => syn::parse_quote! {
-            |app| { app.insert_resource(#expr); }
-        },
-        (Some(expr), true) => syn::parse_quote! {
-            |app| { app.insert_non_send_resource(#expr); }
-        },
-        (None, false) => syn::parse_quote! {
-            |app| { app.init_resource::<#res_ident #generics>(); }
-        },
-        (None, true) => syn::parse_quote! {
-            |app| { app.init_non_send_resource::<#res_ident #generics>(); }
+    let entry_expr = match &attr.scoped_to {
+        Some(state) => scoped_entry_expr(&attr, res_ident, state),
+        None => match (&attr.init, attr.non_send.is_set()) {
+            (Some(expr), false) => syn::parse_quote! {
+                |app| { app.insert_resource(#expr); }
+            },
+            (Some(expr), true) => syn::parse_quote! {
+                |app| { app.world_mut().insert_non_send_resource(#expr); }
+            },
+            (None, false) => syn::parse_quote! {
+                |app| { app.init_resource::<#res_ident #generics>(); }
+            },
+            (None, true) => syn::parse_quote! {
+                |app| { app.world_mut().init_non_send_resource::<#res_ident #generics>(); }
+            },
         },
     };
 
-    let register_block = butler_plugin_entry_block(&static_ident, &attr.plugin, &entry_expr);
+    let register_block = ButlerEntry::new("resource", plugin, quote!(#res_ident #generics))
+        .hash(&attr.scoped_to)
+        .phase(attr.phase)
+        .sub_app(attr.sub_app.as_ref())
+        .generate(&entry_expr);
 
     Ok(quote! {
         #item
@@ -1,10 +1,16 @@
 use deluxe::{Flag, ParseMetaItem};
 use syn::{AngleBracketedGenericArguments, Expr, Path};
 
+use crate::utils::ButlerPhase;
+
 #[derive(ParseMetaItem)]
 pub(crate) struct ResourceAttr {
     pub plugin: Path,
     pub init: Option<Expr>,
     pub non_send: Flag,
     pub generics: Option<AngleBracketedGenericArguments>,
+    pub scoped_to: Option<Expr>,
+    pub sub_app: Option<Expr>,
+    #[deluxe(default)]
+    pub phase: ButlerPhase,
 }
@@ -1,39 +1,73 @@
 use proc_macro::TokenStream as TokenStream1;
 use proc_macro2::TokenStream as TokenStream2;
-use quote::{format_ident, quote, ToTokens};
-use structs::InsertStateAttr;
+use quote::quote;
+use structs::{InsertStateAttr, StateDebugAttr};
 use syn::Item;
 
-use crate::utils::{butler_plugin_entry_block, get_struct_or_enum_ident};
+use crate::utils::{get_struct_or_enum_ident, ButlerEntry};
 
 pub mod structs;
 
+/// Generates the statements enabling a state's debugging options.
+/// `mutable` states also have a `NextState` to register with `reflect`.
+pub(crate) fn state_debug_tokens(
+    debug: &StateDebugAttr,
+    state: &TokenStream2,
+    mutable: bool,
+) -> syn::Result<TokenStream2> {
+    let mut tokens = TokenStream2::new();
+
+    if debug.log_transitions.is_set() {
+        tokens.extend(quote! {
+            ::bevy_butler::__internal::log_state_transitions::<#state>(app.world_mut());
+        });
+    }
+
+    if debug.reflect.is_set() {
+        tokens.extend(quote! {
+            app.register_type::<#state>();
+            app.register_type::<::bevy_butler::__internal::bevy_state::state::State<#state>>();
+        });
+        if mutable {
+            tokens.extend(quote! {
+                app.register_type::<::bevy_butler::__internal::bevy_state::state::NextState<#state>>();
+            });
+        }
+    }
+
+    if let Some(history) = &debug.history {
+        if history.base10_parse::<usize>()? == 0 {
+            return Err(syn::Error::new_spanned(
+                history,
+                "`history` must keep at least one transition",
+            ));
+        }
+        tokens.extend(quote! {
+            ::bevy_butler::__internal::record_state_history::<#state>(app.world_mut(), #history);
+        });
+    }
+
+    Ok(tokens)
+}
+
 pub fn macro_impl(attr: TokenStream1, body: TokenStream1) -> syn::Result<TokenStream2> {
     let attr: InsertStateAttr = deluxe::parse(attr)?;
     let item: Item = syn::parse(body)?;
     let ident = get_struct_or_enum_ident(&item)?;
     let generics = &attr.generics;
+    let debug = state_debug_tokens(&attr.debug, &quote!(#ident #generics), true)?;
 
-    let static_ident = format_ident!(
-        "_butler_state_{}",
-        sha256::digest([
-            attr.plugin.to_token_stream().to_string(),
-            attr.generics.to_token_stream().to_string(),
-        ].concat())
-    );
-
-    let register_block = butler_plugin_entry_block(
-        &static_ident,
-        &attr.plugin,
-        &match attr.init {
+    let register_block = ButlerEntry::new("state", &attr.plugin, quote!(#ident #generics))
+        .phase(attr.phase)
+        .sub_app(attr.sub_app.as_ref())
+        .generate(&match attr.init {
             Some(init) => syn::parse_quote! {
-                |app| { ::bevy_butler::__internal::bevy_state::app::AppExtStates::insert_state::<#ident #generics>(app, #init); }
+                |app| { ::bevy_butler::__internal::bevy_state::app::AppExtStates::insert_state::<#ident #generics>(app, #init); #debug }
             },
             None => syn::parse_quote! {
-                |app| { ::bevy_butler::__internal::bevy_state::app::AppExtStates::init_state::<#ident #generics>(app); }
+                |app| { ::bevy_butler::__internal::bevy_state::app::AppExtStates::init_state::<#ident #generics>(app); #debug }
             }
-        }
-    );
+        });
 
     Ok(quote! {
         #item
@@ -1,9 +1,24 @@
-use deluxe::ParseMetaItem;
-use syn::{AngleBracketedGenericArguments, Expr, Path};
+use deluxe::{Flag, ParseMetaItem};
+use syn::{AngleBracketedGenericArguments, Expr, LitInt, Path};
+
+use crate::utils::ButlerPhase;
 
 #[derive(ParseMetaItem)]
 pub struct InsertStateAttr {
     pub plugin: Path,
     pub generics: Option<AngleBracketedGenericArguments>,
     pub init: Option<Expr>,
+    pub sub_app: Option<Expr>,
+    #[deluxe(default)]
+    pub phase: ButlerPhase,
+    #[deluxe(flatten)]
+    pub debug: StateDebugAttr,
+}
+
+/// Debugging options shared by every state registration macro
+#[derive(ParseMetaItem)]
+pub struct StateDebugAttr {
+    pub log_transitions: Flag,
+    pub reflect: Flag,
+    pub history: Option<LitInt>,
 }
\ No newline at end of file
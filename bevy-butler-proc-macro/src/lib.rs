@@ -23,6 +23,12 @@ pub fn add_system(attr: TokenStream, body: TokenStream) -> TokenStream {
     result_to_tokens(add_system::macro_impl(attr, body))
 }
 
+pub(crate) mod butler_impl;
+#[proc_macro_attribute]
+pub fn butler_impl(attr: TokenStream, body: TokenStream) -> TokenStream {
+    result_to_tokens(butler_impl::macro_impl(attr, body))
+}
+
 pub(crate) mod add_observer;
 #[proc_macro_attribute]
 pub fn add_observer(attr: TokenStream, body: TokenStream) -> TokenStream {
@@ -76,3 +82,174 @@ pub(crate) mod add_sub_state;
 pub fn add_sub_state(attr: TokenStream, body: TokenStream) -> TokenStream {
     result_to_tokens(add_sub_state::macro_impl(attr, body))
 }
+
+pub(crate) mod butler_sub_app;
+#[proc_macro_attribute]
+pub fn butler_sub_app(attr: TokenStream, body: TokenStream) -> TokenStream {
+    result_to_tokens(butler_sub_app::macro_impl(attr, body))
+}
+
+pub(crate) mod butler_phase_fn;
+#[proc_macro_attribute]
+pub fn butler_build(attr: TokenStream, body: TokenStream) -> TokenStream {
+    result_to_tokens(butler_phase_fn::macro_impl(
+        attr,
+        body,
+        utils::ButlerPhase::Build,
+    ))
+}
+
+#[proc_macro_attribute]
+pub fn butler_finish(attr: TokenStream, body: TokenStream) -> TokenStream {
+    result_to_tokens(butler_phase_fn::macro_impl(
+        attr,
+        body,
+        utils::ButlerPhase::Finish,
+    ))
+}
+
+#[proc_macro_attribute]
+pub fn butler_cleanup(attr: TokenStream, body: TokenStream) -> TokenStream {
+    result_to_tokens(butler_phase_fn::macro_impl(
+        attr,
+        body,
+        utils::ButlerPhase::Cleanup,
+    ))
+}
+
+pub(crate) mod add_computed_state;
+#[proc_macro_attribute]
+pub fn add_computed_state(attr: TokenStream, body: TokenStream) -> TokenStream {
+    result_to_tokens(add_computed_state::macro_impl(attr, body))
+}
+
+pub(crate) mod on_state;
+#[proc_macro_attribute]
+pub fn on_enter(attr: TokenStream, body: TokenStream) -> TokenStream {
+    result_to_tokens(on_state::macro_impl(
+        attr,
+        body,
+        on_state::StateSchedule::OnEnter,
+    ))
+}
+
+#[proc_macro_attribute]
+pub fn on_exit(attr: TokenStream, body: TokenStream) -> TokenStream {
+    result_to_tokens(on_state::macro_impl(
+        attr,
+        body,
+        on_state::StateSchedule::OnExit,
+    ))
+}
+
+#[proc_macro_attribute]
+pub fn on_transition(attr: TokenStream, body: TokenStream) -> TokenStream {
+    result_to_tokens(on_state::transition_macro_impl(attr, body))
+}
+
+pub(crate) mod add_system_set;
+#[proc_macro_attribute]
+pub fn add_system_set(attr: TokenStream, body: TokenStream) -> TokenStream {
+    result_to_tokens(add_system_set::macro_impl(attr, body))
+}
+
+pub(crate) mod add_schedule;
+#[proc_macro_attribute]
+pub fn add_schedule(attr: TokenStream, body: TokenStream) -> TokenStream {
+    result_to_tokens(add_schedule::macro_impl(attr, body))
+}
+
+pub(crate) mod configure_schedule;
+#[proc_macro_attribute]
+pub fn configure_schedule(attr: TokenStream, body: TokenStream) -> TokenStream {
+    result_to_tokens(configure_schedule::macro_impl(attr, body))
+}
+
+pub(crate) mod allow_ambiguous;
+#[proc_macro_attribute]
+pub fn allow_ambiguous_resource(attr: TokenStream, body: TokenStream) -> TokenStream {
+    result_to_tokens(allow_ambiguous::macro_impl(
+        attr,
+        body,
+        allow_ambiguous::AmbiguousKind::Resource,
+    ))
+}
+
+#[proc_macro_attribute]
+pub fn allow_ambiguous_component(attr: TokenStream, body: TokenStream) -> TokenStream {
+    result_to_tokens(allow_ambiguous::macro_impl(
+        attr,
+        body,
+        allow_ambiguous::AmbiguousKind::Component,
+    ))
+}
+
+pub(crate) mod ignore_ambiguity;
+#[proc_macro_attribute]
+pub fn ignore_ambiguity(attr: TokenStream, body: TokenStream) -> TokenStream {
+    result_to_tokens(ignore_ambiguity::macro_impl(attr, body))
+}
+
+pub(crate) mod register_system;
+#[proc_macro_attribute]
+pub fn register_system(attr: TokenStream, body: TokenStream) -> TokenStream {
+    result_to_tokens(register_system::macro_impl(attr, body))
+}
+
+pub(crate) mod component_hook;
+#[proc_macro_attribute]
+pub fn on_add(attr: TokenStream, body: TokenStream) -> TokenStream {
+    result_to_tokens(component_hook::macro_impl(
+        attr,
+        body,
+        component_hook::HookKind::Add,
+    ))
+}
+
+#[proc_macro_attribute]
+pub fn on_insert(attr: TokenStream, body: TokenStream) -> TokenStream {
+    result_to_tokens(component_hook::macro_impl(
+        attr,
+        body,
+        component_hook::HookKind::Insert,
+    ))
+}
+
+#[proc_macro_attribute]
+pub fn on_replace(attr: TokenStream, body: TokenStream) -> TokenStream {
+    result_to_tokens(component_hook::macro_impl(
+        attr,
+        body,
+        component_hook::HookKind::Replace,
+    ))
+}
+
+#[proc_macro_attribute]
+pub fn on_remove(attr: TokenStream, body: TokenStream) -> TokenStream {
+    result_to_tokens(component_hook::macro_impl(
+        attr,
+        body,
+        component_hook::HookKind::Remove,
+    ))
+}
+
+#[proc_macro_attribute]
+pub fn on_despawn(attr: TokenStream, body: TokenStream) -> TokenStream {
+    result_to_tokens(component_hook::macro_impl(
+        attr,
+        body,
+        component_hook::HookKind::Despawn,
+    ))
+}
+
+pub(crate) mod register_required_components;
+#[proc_macro_attribute]
+pub fn register_required_components(attr: TokenStream, body: TokenStream) -> TokenStream {
+    result_to_tokens(register_required_components::macro_impl(attr, body))
+}
+
+pub(crate) mod register_component;
+#[proc_macro_attribute]
+pub fn register_component(attr: TokenStream, body: TokenStream) -> TokenStream {
+    result_to_tokens(register_component::macro_impl(attr, body))
+}
@@ -0,0 +1,129 @@
+use proc_macro::TokenStream as TokenStream1;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use structs::{OnStateAttr, OnTransitionAttr};
+use syn::{AngleBracketedGenericArguments, Expr, Ident, Item, Path};
+
+use crate::add_system::structs::TransformList;
+use crate::add_system::{add_systems_stmts, parse_system, with_transforms};
+use crate::utils::{get_fn_ident, ButlerEntry, ButlerPhase};
+
+pub(crate) mod structs;
+
+/// The state schedule a system is added to by `#[on_enter]` or `#[on_exit]`
+#[derive(Clone, Copy)]
+pub(crate) enum StateSchedule {
+    OnEnter,
+    OnExit,
+}
+
+/// The arguments `on_enter`, `on_exit` and `on_transition` share with `add_system`
+struct SystemArgs<'a> {
+    plugin: &'a Path,
+    generics: Option<&'a AngleBracketedGenericArguments>,
+    pipe_in: Option<&'a Vec<Expr>>,
+    error_handler: Option<&'a Expr>,
+    sub_app: Option<&'a Expr>,
+    phase: ButlerPhase,
+    transforms: &'a TransformList,
+}
+
+fn system_entry(args: SystemArgs, ident: &Ident, schedules: &[Expr]) -> TokenStream2 {
+    let system = parse_system(ident, args.generics, args.pipe_in);
+    let sys_expr = with_transforms(&system, args.transforms);
+    let stmts = add_systems_stmts(
+        args.plugin,
+        ident,
+        &system,
+        args.transforms,
+        schedules,
+        args.error_handler,
+    );
+
+    let entry = ButlerEntry::new("system", args.plugin, &sys_expr)
+        .hash(quote!(#(#schedules),*))
+        .hash(args.error_handler);
+    schedules
+        .iter()
+        .fold(entry, ButlerEntry::schedule)
+        .phase(args.phase)
+        .sub_app(args.sub_app)
+        .generate(&syn::parse_quote! {
+            |app| { #stmts }
+        })
+}
+
+pub(crate) fn macro_impl(
+    attr: TokenStream1,
+    item: TokenStream1,
+    schedule: StateSchedule,
+) -> syn::Result<TokenStream2> {
+    let attr: OnStateAttr = deluxe::parse(attr)?;
+    let input: Item = syn::parse(item)?;
+
+    let sys_ident = get_fn_ident(&input)?;
+
+    let schedules: Vec<Expr> = attr
+        .state
+        .0
+        .iter()
+        .map(|state| match schedule {
+            StateSchedule::OnEnter => {
+                syn::parse_quote!(::bevy_butler::__internal::bevy_state::prelude::OnEnter(#state))
+            }
+            StateSchedule::OnExit => {
+                syn::parse_quote!(::bevy_butler::__internal::bevy_state::prelude::OnExit(#state))
+            }
+        })
+        .collect();
+
+    let args = SystemArgs {
+        plugin: &attr.plugin,
+        generics: attr.generics.as_ref(),
+        pipe_in: attr.pipe_in.as_ref(),
+        error_handler: attr.error_handler.as_ref(),
+        sub_app: attr.sub_app.as_ref(),
+        phase: attr.phase,
+        transforms: &attr.transforms,
+    };
+    let register_block = system_entry(args, sys_ident, &schedules);
+
+    Ok(quote! {
+        #input
+
+        #register_block
+    })
+}
+
+pub(crate) fn transition_macro_impl(
+    attr: TokenStream1,
+    item: TokenStream1,
+) -> syn::Result<TokenStream2> {
+    let attr: OnTransitionAttr = deluxe::parse(attr)?;
+    let input: Item = syn::parse(item)?;
+
+    let sys_ident = get_fn_ident(&input)?;
+
+    let from = &attr.from;
+    let to = &attr.to;
+    let schedule: Expr = syn::parse_quote! {
+        ::bevy_butler::__internal::bevy_state::prelude::OnTransition { exited: #from, entered: #to }
+    };
+
+    let args = SystemArgs {
+        plugin: &attr.plugin,
+        generics: attr.generics.as_ref(),
+        pipe_in: attr.pipe_in.as_ref(),
+        error_handler: attr.error_handler.as_ref(),
+        sub_app: attr.sub_app.as_ref(),
+        phase: attr.phase,
+        transforms: &attr.transforms,
+    };
+    let register_block = system_entry(args, sys_ident, &[schedule]);
+
+    Ok(quote! {
+        #input
+
+        #register_block
+    })
+}
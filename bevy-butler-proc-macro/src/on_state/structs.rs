@@ -0,0 +1,34 @@
+use deluxe::ParseMetaItem;
+use syn::{AngleBracketedGenericArguments, Expr, Path};
+
+use crate::add_system::structs::TransformList;
+use crate::utils::{ButlerPhase, ExprList};
+
+#[derive(Clone, ParseMetaItem)]
+pub(crate) struct OnStateAttr {
+    pub plugin: Path,
+    pub state: ExprList,
+    pub generics: Option<AngleBracketedGenericArguments>,
+    pub pipe_in: Option<Vec<Expr>>,
+    pub error_handler: Option<Expr>,
+    pub sub_app: Option<Expr>,
+    #[deluxe(default)]
+    pub phase: ButlerPhase,
+    #[deluxe(rest)]
+    pub transforms: TransformList,
+}
+
+#[derive(Clone, ParseMetaItem)]
+pub(crate) struct OnTransitionAttr {
+    pub plugin: Path,
+    pub from: Expr,
+    pub to: Expr,
+    pub generics: Option<AngleBracketedGenericArguments>,
+    pub pipe_in: Option<Vec<Expr>>,
+    pub error_handler: Option<Expr>,
+    pub sub_app: Option<Expr>,
+    #[deluxe(default)]
+    pub phase: ButlerPhase,
+    #[deluxe(rest)]
+    pub transforms: TransformList,
+}
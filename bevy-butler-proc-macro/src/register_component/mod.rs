@@ -0,0 +1,37 @@
+use proc_macro::TokenStream as TokenStream1;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use structs::RegisterComponentAttr;
+use syn::Item;
+
+use crate::utils::{get_struct_or_enum_ident, ButlerEntry};
+
+pub(crate) mod structs;
+
+pub(crate) fn macro_impl(attr: TokenStream1, body: TokenStream1) -> syn::Result<TokenStream2> {
+    let attr: RegisterComponentAttr = deluxe::parse(attr)?;
+    let item: Item = syn::parse(body)?;
+    let type_ident = get_struct_or_enum_ident(&item)?;
+
+    let plugin = &attr.plugin;
+    let queue = match attr.resource.is_set() {
+        true => quote!(queue_resource),
+        false => quote!(queue_component),
+    };
+
+    let register_block = ButlerEntry::new("component", plugin, type_ident)
+        .phase(attr.phase)
+        .sub_app(attr.sub_app.as_ref())
+        .early()
+        .generate(&syn::parse_quote! {
+            |app| {
+                ::bevy_butler::__internal::#queue::<#type_ident>(app.world_mut());
+            }
+        });
+
+    Ok(quote! {
+        #item
+
+        #register_block
+    })
+}
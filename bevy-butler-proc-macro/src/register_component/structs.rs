@@ -0,0 +1,13 @@
+use deluxe::{Flag, ParseMetaItem};
+use syn::{Expr, Path};
+
+use crate::utils::ButlerPhase;
+
+#[derive(ParseMetaItem)]
+pub(crate) struct RegisterComponentAttr {
+    pub plugin: Path,
+    pub resource: Flag,
+    pub sub_app: Option<Expr>,
+    #[deluxe(default)]
+    pub phase: ButlerPhase,
+}
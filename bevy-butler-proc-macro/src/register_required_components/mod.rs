@@ -0,0 +1,51 @@
+use proc_macro::TokenStream as TokenStream1;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use structs::RequiredComponentsAttr;
+use syn::{Error, ExprClosure, Item};
+
+use crate::utils::{get_struct_or_enum_ident, ButlerEntry};
+
+pub(crate) mod structs;
+
+pub(crate) fn macro_impl(attr: TokenStream1, body: TokenStream1) -> syn::Result<TokenStream2> {
+    let attr: RequiredComponentsAttr = deluxe::parse(attr)?;
+    let item: Item = syn::parse(body)?;
+    let type_ident = get_struct_or_enum_ident(&item)?;
+
+    let plugin = &attr.plugin;
+    let requires = &attr.requires.0;
+
+    let entry_expr: ExprClosure = match &attr.constructor {
+        Some(constructor) => {
+            let [required] = requires.as_slice() else {
+                return Err(Error::new_spanned(
+                    constructor,
+                    "`constructor` can only be used with a single required component",
+                ));
+            };
+            syn::parse_quote! {
+                |app| {
+                    app.world_mut().register_required_components_with::<#type_ident, #required>(#constructor);
+                }
+            }
+        }
+        None => syn::parse_quote! {
+            |app| {
+                #(app.world_mut().register_required_components::<#type_ident, #requires>();)*
+            }
+        },
+    };
+
+    let register_block = ButlerEntry::new("required_components", plugin, type_ident)
+        .hash(quote!(#(#requires)*))
+        .phase(attr.phase)
+        .sub_app(attr.sub_app.as_ref())
+        .generate(&entry_expr);
+
+    Ok(quote! {
+        #item
+
+        #register_block
+    })
+}
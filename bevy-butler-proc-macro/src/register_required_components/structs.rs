@@ -0,0 +1,14 @@
+use deluxe::ParseMetaItem;
+use syn::{Expr, Path};
+
+use crate::utils::{ButlerPhase, TypeList};
+
+#[derive(ParseMetaItem)]
+pub(crate) struct RequiredComponentsAttr {
+    pub plugin: Path,
+    pub requires: TypeList,
+    pub constructor: Option<Expr>,
+    pub sub_app: Option<Expr>,
+    #[deluxe(default)]
+    pub phase: ButlerPhase,
+}
@@ -0,0 +1,105 @@
+use proc_macro::TokenStream as TokenStream1;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use structs::RegisterSystemAttr;
+use syn::{
+    parse_quote, Error, ExprClosure, FnArg, GenericArgument, Ident, ItemFn, PathArguments,
+    ReturnType, Type,
+};
+
+use crate::utils::ButlerEntry;
+
+pub(crate) mod structs;
+
+/// `spawn_wave` -> `SpawnWave`
+fn handle_ident(ident: &Ident) -> Ident {
+    let name: String = ident
+        .to_string()
+        .split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect();
+    format_ident!("{name}", span = ident.span())
+}
+
+/// The `SystemInput` of the system, if its first parameter is `In`, `InRef` or `InMut`.
+/// Lifetimes are made `'static`, so the `SystemId` can be stored in a resource.
+fn system_input(item: &ItemFn) -> Option<Type> {
+    let Some(FnArg::Typed(arg)) = item.sig.inputs.first() else {
+        return None;
+    };
+    let Type::Path(mut ty) = (*arg.ty).clone() else {
+        return None;
+    };
+    let last = ty.path.segments.last_mut()?;
+    if !["In", "InRef", "InMut"].contains(&last.ident.to_string().as_str()) {
+        return None;
+    }
+    if let PathArguments::AngleBracketed(args) = &mut last.arguments {
+        for arg in args.args.iter_mut() {
+            if let GenericArgument::Lifetime(lifetime) = arg {
+                *lifetime = parse_quote!('static);
+            }
+        }
+    }
+    Some(Type::Path(ty))
+}
+
+pub(crate) fn macro_impl(attr: TokenStream1, body: TokenStream1) -> syn::Result<TokenStream2> {
+    let attr: RegisterSystemAttr = deluxe::parse(attr)?;
+    let item = syn::parse::<ItemFn>(body.clone()).map_err(|_| {
+        Error::new_spanned(
+            TokenStream2::from(body),
+            "`register_system` can only be used on a function",
+        )
+    })?;
+    if !item.sig.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &item.sig.generics,
+            "`register_system` can't be used on a generic function",
+        ));
+    }
+
+    let plugin = &attr.plugin;
+    let vis = &item.vis;
+    let ident = &item.sig.ident;
+    let handle = attr.handle.clone().unwrap_or_else(|| handle_ident(ident));
+
+    let input = system_input(&item).unwrap_or_else(|| parse_quote!(()));
+    let output: Type = match &item.sig.output {
+        ReturnType::Default => parse_quote!(()),
+        ReturnType::Type(_, ty) => (**ty).clone(),
+    };
+    let system_id = quote!(::bevy_butler::__internal::bevy_ecs::system::SystemId<#input, #output>);
+
+    let doc = format!("The `SystemId` of the one-shot system [`{ident}`].");
+
+    let entry_expr: ExprClosure = parse_quote! {
+        |app| {
+            let id = app.world_mut().register_system( #ident );
+            app.world_mut().insert_resource( #handle(id) );
+        }
+    };
+
+    let register_block = ButlerEntry::new("register_system", plugin, ident)
+        .phase(attr.phase)
+        .sub_app(attr.sub_app.as_ref())
+        .generate(&entry_expr);
+
+    Ok(quote! {
+        #item
+
+        #[doc = #doc]
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        #vis struct #handle(pub #system_id);
+
+        impl ::bevy_butler::__internal::bevy_ecs::resource::Resource for #handle {}
+
+        #register_block
+    })
+}
@@ -0,0 +1,13 @@
+use deluxe::ParseMetaItem;
+use syn::{Expr, Ident, Path};
+
+use crate::utils::ButlerPhase;
+
+#[derive(ParseMetaItem)]
+pub(crate) struct RegisterSystemAttr {
+    pub plugin: Path,
+    pub handle: Option<Ident>,
+    pub sub_app: Option<Expr>,
+    #[deluxe(default)]
+    pub phase: ButlerPhase,
+}
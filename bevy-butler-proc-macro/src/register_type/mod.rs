@@ -1,41 +1,30 @@
 use proc_macro::TokenStream as TokenStream1;
 use proc_macro2::TokenStream as TokenStream2;
-use quote::{format_ident, quote};
+use quote::quote;
 use structs::RegisterTypeAttr;
-use syn::{Error, Item};
+use syn::Item;
 
-use crate::utils::{butler_plugin_entry_block, get_use_path};
+use crate::utils::{get_struct_or_enum_ident, ButlerEntry};
 
 pub(crate) mod structs;
 
 pub(crate) fn macro_impl(attr: TokenStream1, body: TokenStream1) -> syn::Result<TokenStream2> {
     let attr: RegisterTypeAttr = deluxe::parse(attr)?;
     let item: Item = syn::parse(body)?;
-    let type_ident = match &item {
-        Item::Struct(i_struct) => &i_struct.ident,
-        Item::Use(i_use) => get_use_path(&i_use.tree)?,
-        Item::Type(i_type) => &i_type.ident,
-        Item::Enum(i_enum) => &i_enum.ident,
-        item => {
-            return Err(Error::new_spanned(
-                item,
-                "Expected a `struct`, `use`, `enum` or `type` item",
-            ))
-        }
-    };
+    let type_ident = get_struct_or_enum_ident(&item)?;
 
     let plugin = &attr.plugin;
     let type_data = &attr.type_data;
 
-    let static_ident = format_ident!("_butler_typereg_{}", sha256::digest(type_ident.to_string()));
-    let entry_expr = syn::parse_quote! {
-        |app| {
-            app.register_type::<#type_ident>()#(
-                .register_type_data::<#type_ident, #type_data>())*;
-        }
-    };
-
-    let register_block = butler_plugin_entry_block(&static_ident, plugin, &entry_expr);
+    let register_block = ButlerEntry::new("typereg", plugin, type_ident)
+        .phase(attr.phase)
+        .sub_app(attr.sub_app.as_ref())
+        .generate(&syn::parse_quote! {
+            |app| {
+                app.register_type::<#type_ident>()#(
+                    .register_type_data::<#type_ident, #type_data>())*;
+            }
+        });
 
     Ok(quote! {
         #item
@@ -1,9 +1,14 @@
 use deluxe::ParseMetaItem;
-use syn::Path;
+use syn::{Expr, Path};
+
+use crate::utils::ButlerPhase;
 
 #[derive(ParseMetaItem)]
 pub(crate) struct RegisterTypeAttr {
     pub plugin: Path,
     #[deluxe(default)]
     pub type_data: Vec<Path>,
+    pub sub_app: Option<Expr>,
+    #[deluxe(default)]
+    pub phase: ButlerPhase,
 }
@@ -1,19 +1,16 @@
+use deluxe::{ParseMetaItem, ParseMode};
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::{Error, ExprClosure, Ident, Item, Path, UseTree};
+use syn::parse::ParseStream;
+use syn::punctuated::Punctuated;
+use syn::{
+    bracketed, parse_quote, Error, Expr, ExprClosure, Fields, Ident, Item, ItemStruct, Path, Token,
+    Type,
+};
 
-pub(crate) fn butler_plugin_entry_block(
-    static_ident: &Ident,
-    plugin: &Path,
-    expr: &ExprClosure,
-) -> TokenStream {
-    quote! {
-        ::bevy_butler::_butler_plugin_entry!(#static_ident, ::bevy_butler::__internal::ButlerPluginRegistryEntryFactory::new(
-            || #plugin::_butler_plugin_sealed_marker(),
-            #expr
-        ));
-    }
-}
+pub(crate) use bevy_butler_codegen::{
+    get_fn_ident, get_struct_or_enum_ident, ButlerEntry, ButlerPhase,
+};
 
 pub(crate) fn butler_plugin_group_entry_block(
     static_ident: &Ident,
@@ -28,31 +25,60 @@ pub(crate) fn butler_plugin_group_entry_block(
     }
 }
 
-pub(crate) fn get_use_path(tree: &UseTree) -> syn::Result<&Ident> {
-    match tree {
-        UseTree::Path(path) => get_use_path(&path.tree),
-        UseTree::Name(name) => Ok(&name.ident),
-        UseTree::Rename(rename) => Ok(&rename.rename),
-        UseTree::Group(_) | UseTree::Glob(_) => {
-            Err(syn::Error::new_spanned(tree, "Expected a path"))
-        }
+/// Returns the expression used as a label for `item` when no explicit `label` is given.
+/// Unit structs, imported types and type aliases can be used as their own label.
+pub(crate) fn default_label(item: &Item) -> syn::Result<Expr> {
+    let ident = get_struct_or_enum_ident(item)?;
+    match item {
+        Item::Struct(ItemStruct {
+            fields: Fields::Unit,
+            ..
+        })
+        | Item::Use(_)
+        | Item::Type(_) => Ok(parse_quote!(#ident)),
+        other => Err(Error::new_spanned(
+            other,
+            "Expected a unit struct, or a `label` argument",
+        )),
     }
 }
 
-pub(crate) fn get_struct_or_enum_ident(item: &Item) -> syn::Result<&Ident> {
-    match item {
-        Item::Struct(i) => Ok(&i.ident),
-        Item::Enum(i) => Ok(&i.ident),
-        Item::Use(i) => get_use_path(&i.tree),
-        Item::Type(i) => Ok(&i.ident),
-        other => Err(Error::new_spanned(other, "Expected a struct, enum, type alias or use statement")),
+/// Either a single expression, or a non-empty array of expressions
+#[derive(Clone)]
+pub(crate) struct ExprList(pub Vec<Expr>);
+
+impl ParseMetaItem for ExprList {
+    fn parse_meta_item(input: ParseStream, mode: ParseMode) -> deluxe::Result<Self> {
+        match Expr::parse_meta_item(input, mode)? {
+            Expr::Array(array) => {
+                if array.elems.is_empty() {
+                    return Err(Error::new_spanned(array, "Expected at least one value"));
+                }
+                Ok(Self(array.elems.into_iter().collect()))
+            }
+            expr => Ok(Self(vec![expr])),
+        }
     }
 }
 
-pub(crate) fn get_fn_ident(item: &Item) -> syn::Result<&Ident> {
-    match item {
-        Item::Fn(i) => Ok(&i.sig.ident),
-        Item::Use(i) => get_use_path(&i.tree),
-        other => Err(Error::new_spanned(other, "Expected a function or use statement")),
+/// Either a single type, or a non-empty array of types
+#[derive(Clone)]
+pub(crate) struct TypeList(pub Vec<Type>);
+
+impl ParseMetaItem for TypeList {
+    fn parse_meta_item(input: ParseStream, _mode: ParseMode) -> deluxe::Result<Self> {
+        if !input.peek(syn::token::Bracket) {
+            return Ok(Self(vec![input.parse()?]));
+        }
+        let content;
+        let bracket = bracketed!(content in input);
+        let types = Punctuated::<Type, Token![,]>::parse_terminated(&content)?;
+        if types.is_empty() {
+            return Err(Error::new(
+                bracket.span.join(),
+                "Expected at least one type",
+            ));
+        }
+        Ok(Self(types.into_iter().collect()))
     }
 }
@@ -0,0 +1,29 @@
+use std::any::type_name;
+
+use bevy_ecs::prelude::*;
+
+use crate::component::{PendingComponent, PendingComponents};
+
+/// Queues `C` to be registered once the current phase of the plugin has run.
+pub fn queue_component<C: Component>(world: &mut World) {
+    world
+        .get_resource_or_init::<PendingComponents>()
+        .0
+        .push(PendingComponent {
+            type_name: type_name::<C>(),
+            register: World::register_component::<C>,
+            resource: false,
+        });
+}
+
+/// Queues `R` to be registered once the current phase of the plugin has run.
+pub fn queue_resource<R: Resource>(world: &mut World) {
+    world
+        .get_resource_or_init::<PendingComponents>()
+        .0
+        .push(PendingComponent {
+            type_name: type_name::<R>(),
+            register: World::register_resource::<R>,
+            resource: true,
+        });
+}
@@ -0,0 +1,73 @@
+use bevy_ecs::component::Tick;
+use bevy_ecs::error::{BevyError, ErrorContext};
+use bevy_ecs::system::{
+    Adapt, IntoAdapterSystem, IntoSystem, RunSystemError, System, SystemIn, SystemInput,
+};
+
+use crate::registry::ButlerEntryInfo;
+
+/// The signature of Bevy's error handlers, like [`warn`](bevy_ecs::error::warn) or [`panic`](bevy_ecs::error::panic).
+pub type ButlerErrorHandler = fn(BevyError, ErrorContext);
+
+/// Passes the errors of a system or observer to an error handler, instead of the default one.
+#[derive(Clone)]
+pub struct ButlerErrorRoute {
+    handler: ButlerErrorHandler,
+    plugin: &'static str,
+    info: ButlerEntryInfo,
+    observer: bool,
+}
+
+impl<S: System<Out = ()>> Adapt<S> for ButlerErrorRoute {
+    type In = S::In;
+    type Out = ();
+
+    fn adapt(
+        &mut self,
+        input: <Self::In as SystemInput>::Inner<'_>,
+        run_system: impl FnOnce(SystemIn<'_, S>) -> Result<S::Out, RunSystemError>,
+    ) -> Result<Self::Out, RunSystemError> {
+        let Err(RunSystemError::Failed(error)) = run_system(input) else {
+            return Ok(());
+        };
+
+        let ButlerEntryInfo {
+            name, file, line, ..
+        } = self.info;
+        let name = format!("{name} ({}, {file}:{line})", self.plugin).into();
+        // The wrapped system's last run isn't visible to adapters
+        let last_run = Tick::new(0);
+        (self.handler)(
+            error,
+            match self.observer {
+                true => ErrorContext::Observer { name, last_run },
+                false => ErrorContext::System { name, last_run },
+            },
+        );
+        Ok(())
+    }
+}
+
+/// Wraps `system` so its errors are passed to `handler`, with an [`ErrorContext`] naming the plugin and
+/// the location of the entry along with the system.
+pub fn route_errors<S, I, M>(
+    system: S,
+    handler: ButlerErrorHandler,
+    plugin: &'static str,
+    info: ButlerEntryInfo,
+    observer: bool,
+) -> IntoAdapterSystem<ButlerErrorRoute, S>
+where
+    S: IntoSystem<I, (), M>,
+    I: SystemInput,
+{
+    IntoAdapterSystem::new(
+        ButlerErrorRoute {
+            handler,
+            plugin,
+            info,
+            observer,
+        },
+        system,
+    )
+}
@@ -0,0 +1,77 @@
+use std::collections::HashMap;
+
+use bevy_ecs::component::ComponentId;
+use bevy_ecs::lifecycle::{ComponentHook, ComponentHooks};
+use bevy_ecs::prelude::*;
+
+use crate::registry::ButlerEntryInfo;
+
+/// The component lifecycle hooks that can be registered with `#[on_add]` and friends.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ButlerHookKind {
+    Add,
+    Insert,
+    Replace,
+    Remove,
+    Despawn,
+}
+
+impl ButlerHookKind {
+    fn name(self) -> &'static str {
+        match self {
+            ButlerHookKind::Add => "on_add",
+            ButlerHookKind::Insert => "on_insert",
+            ButlerHookKind::Replace => "on_replace",
+            ButlerHookKind::Remove => "on_remove",
+            ButlerHookKind::Despawn => "on_despawn",
+        }
+    }
+
+    fn try_set(self, hooks: &mut ComponentHooks, hook: ComponentHook) -> bool {
+        match self {
+            ButlerHookKind::Add => hooks.try_on_add(hook),
+            ButlerHookKind::Insert => hooks.try_on_insert(hook),
+            ButlerHookKind::Replace => hooks.try_on_replace(hook),
+            ButlerHookKind::Remove => hooks.try_on_remove(hook),
+            ButlerHookKind::Despawn => hooks.try_on_despawn(hook),
+        }
+        .is_some()
+    }
+}
+
+/// The butler entries that set each component hook, for reporting conflicts
+#[derive(Resource, Default)]
+struct ButlerComponentHooks(HashMap<(ComponentId, ButlerHookKind), ButlerEntryInfo>);
+
+/// Sets the `kind` hook of `C`, panicking with both sources if it was already set.
+pub fn register_component_hook<C: Component>(
+    world: &mut World,
+    kind: ButlerHookKind,
+    hook: ComponentHook,
+    info: ButlerEntryInfo,
+) {
+    let id = world.register_component::<C>();
+    if kind.try_set(world.register_component_hooks::<C>(), hook) {
+        world
+            .get_resource_or_init::<ButlerComponentHooks>()
+            .0
+            .insert((id, kind), info);
+        return;
+    }
+
+    let existing = match world
+        .get_resource::<ButlerComponentHooks>()
+        .and_then(|hooks| hooks.0.get(&(id, kind)))
+    {
+        Some(other) => format!("`{}` at {}:{}", other.name, other.file, other.line),
+        None => "the component's own definition".to_string(),
+    };
+    panic!(
+        "`{}` at {}:{} tried to set the {} hook of `{}`, but it was already set by {existing}",
+        info.name,
+        info.file,
+        info.line,
+        kind.name(),
+        std::any::type_name::<C>(),
+    );
+}
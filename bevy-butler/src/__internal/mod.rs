@@ -7,9 +7,35 @@ pub use bevy_app;
 pub use bevy_ecs;
 pub use bevy_log;
 pub use bevy_state;
+pub use bevy_tasks;
+pub use bevy_time;
 
 mod plugin;
 pub use plugin::*;
 
 mod plugin_group;
 pub use plugin_group::*;
+
+mod sub_app;
+pub use sub_app::*;
+
+mod state;
+pub use state::*;
+
+mod observer;
+pub use observer::*;
+
+mod hooks;
+pub use hooks::*;
+
+mod component;
+pub use component::*;
+
+mod error;
+pub use error::*;
+
+mod timer;
+pub use timer::*;
+
+mod task;
+pub use task::*;
@@ -0,0 +1,104 @@
+use std::marker::PhantomData;
+
+use bevy_ecs::prelude::*;
+use bevy_ecs::system::{
+    CombinatorSystem, Combine, IntoObserverSystem, ObserverSystem, RunSystemError, SystemIn,
+    SystemInput, SystemParamValidationError,
+};
+
+/// The observers `#[add_observer(watch = C)]` spawned for the entity this is on
+#[derive(Component)]
+struct ButlerWatchObservers<C: Component> {
+    observers: Vec<Entity>,
+    marker: PhantomData<fn() -> C>,
+}
+
+/// Whether the observer despawning watch observers of `C` was already added
+#[derive(Resource)]
+struct ButlerWatchedComponent<C: Component>(PhantomData<fn() -> C>);
+
+fn despawn_watch_observers<C: Component>(remove: On<Remove, C>, mut commands: Commands) {
+    let watched = remove.entity;
+    commands.queue(move |world: &mut World| {
+        // Despawning the watched entity already despawns the observers targeting it
+        let Ok(mut entity) = world.get_entity_mut(watched) else {
+            return;
+        };
+        if let Some(watch) = entity.take::<ButlerWatchObservers<C>>() {
+            for observer in watch.observers {
+                world.despawn(observer);
+            }
+        }
+    });
+}
+
+/// Calls `spawn` for every entity that gains `C`, and despawns the observer it returns
+/// once the entity loses `C` again.
+pub fn watch_component<C: Component>(
+    world: &mut World,
+    spawn: fn(&mut Commands, Entity) -> Entity,
+) {
+    world.add_observer(move |add: On<Add, C>, mut commands: Commands| {
+        let watched = add.entity;
+        let observer = spawn(&mut commands, watched);
+        commands.queue(move |world: &mut World| {
+            let Ok(mut entity) = world.get_entity_mut(watched) else {
+                return;
+            };
+            match entity.get_mut::<ButlerWatchObservers<C>>() {
+                Some(mut watch) => watch.observers.push(observer),
+                None => {
+                    entity.insert(ButlerWatchObservers::<C> {
+                        observers: vec![observer],
+                        marker: PhantomData,
+                    });
+                }
+            }
+        });
+    });
+    if !world.contains_resource::<ButlerWatchedComponent<C>>() {
+        world.insert_resource(ButlerWatchedComponent::<C>(PhantomData));
+        world.add_observer(despawn_watch_observers::<C>);
+    }
+}
+
+/// Runs the observer `B` only if the run condition `A` returns `true`.
+///
+/// Observers don't support run conditions themselves, so this skips the observer the same way
+/// a failed parameter validation would.
+pub struct ObserverRunIf;
+
+impl<A, B> Combine<A, B> for ObserverRunIf
+where
+    A: System<In = (), Out = bool>,
+    B: System,
+{
+    type In = B::In;
+    type Out = B::Out;
+
+    fn combine<T>(
+        input: <Self::In as SystemInput>::Inner<'_>,
+        data: &mut T,
+        a: impl FnOnce(SystemIn<'_, A>, &mut T) -> Result<A::Out, RunSystemError>,
+        b: impl FnOnce(SystemIn<'_, B>, &mut T) -> Result<B::Out, RunSystemError>,
+    ) -> Result<Self::Out, RunSystemError> {
+        if a((), data)? {
+            b(input, data)
+        } else {
+            Err(RunSystemError::Skipped(
+                SystemParamValidationError::skipped::<Self>("run condition returned false"),
+            ))
+        }
+    }
+}
+
+/// Wraps `observer` so it only runs if `condition` returns `true`.
+pub fn observer_run_if<E: Event, B: Bundle, M, Out, CM>(
+    observer: impl IntoObserverSystem<E, B, M, Out>,
+    condition: impl SystemCondition<CM>,
+) -> impl ObserverSystem<E, B, Out> {
+    let observer = IntoObserverSystem::into_system(observer);
+    let condition = IntoSystem::into_system(condition);
+    let name = observer.name();
+    CombinatorSystem::<ObserverRunIf, _, _>::new(condition, observer, name)
+}
@@ -1,62 +1,52 @@
 use bevy_app::{App, Plugin};
+use bevy_ecs::schedule::Schedule;
 use std::{
     any::{type_name, TypeId},
     collections::HashMap,
     sync::LazyLock,
 };
 
-pub struct ButlerPluginRegistryEntryFactory(fn() -> TypeId, fn(&mut bevy_app::App));
+use crate::registry::{ButlerEntry, ButlerPhase, ButlerStage};
 
-impl ButlerPluginRegistryEntryFactory {
-    pub const fn new(type_factory: fn() -> TypeId, sys_factory: fn(&mut bevy_app::App)) -> Self {
-        ButlerPluginRegistryEntryFactory(type_factory, sys_factory)
-    }
-}
-
-pub struct ButlerPluginRegistry(HashMap<TypeId, Vec<fn(&mut App)>>);
+pub struct ButlerPluginRegistry(HashMap<(TypeId, ButlerPhase), Vec<&'static ButlerEntry>>);
 
 impl ButlerPluginRegistry {
-    pub(crate) fn get_system_factories(
+    pub(crate) fn get_entries(
         &'static self,
         marker: TypeId,
-    ) -> &'static [fn(&mut bevy_app::App)] {
+        phase: ButlerPhase,
+    ) -> &'static [&'static ButlerEntry] {
         self.0
-            .get(&marker)
+            .get(&(marker, phase))
             .map(|v| v.as_slice())
             .unwrap_or_default()
     }
 }
 
-#[cfg(not(any(target_arch = "wasm32", feature = "inventory")))]
-#[linkme::distributed_slice]
-pub static BUTLER_SLICE: [ButlerPluginRegistryEntryFactory] = [..];
-
-#[cfg(any(target_arch = "wasm32", feature = "inventory"))]
-::inventory::collect!(ButlerPluginRegistryEntryFactory);
-
 pub static BUTLER_PLUGIN_REGISTRY: LazyLock<ButlerPluginRegistry> = LazyLock::new(|| {
     #[cfg(target_arch = "wasm32")]
     crate::_initialize();
 
     #[cfg(not(any(target_arch = "wasm32", feature = "inventory")))]
-    let iter = BUTLER_SLICE.into_iter();
+    let iter = crate::registry::BUTLER_ENTRIES.into_iter();
     #[cfg(any(target_arch = "wasm32", feature = "inventory"))]
-    let iter = ::inventory::iter::<ButlerPluginRegistryEntryFactory>.into_iter();
+    let iter = ::inventory::iter::<ButlerEntry>.into_iter();
 
     let mut count = 0;
-    let mut registry: HashMap<TypeId, Vec<fn(&mut App)>> = HashMap::new();
-    iter.for_each(
-        |ButlerPluginRegistryEntryFactory(type_factory, sys_factory)| {
-            registry
-                .entry(type_factory())
-                .or_default()
-                .push(*sys_factory);
-            count += 1;
-        },
-    );
+    let mut registry: HashMap<(TypeId, ButlerPhase), Vec<&'static ButlerEntry>> = HashMap::new();
+    iter.for_each(|entry| {
+        registry
+            .entry((entry.plugin_marker(), entry.phase()))
+            .or_default()
+            .push(entry);
+        count += 1;
+    });
 
-    // Trim down
-    registry.values_mut().for_each(|vec| vec.shrink_to_fit());
+    // Trim down, and sort the entries by stage
+    registry.values_mut().for_each(|vec| {
+        vec.sort_by_key(|entry| entry.stage());
+        vec.shrink_to_fit();
+    });
     registry.shrink_to_fit();
 
     bevy_log::debug!("Building ButlerRegistry from {count} entries");
@@ -65,34 +55,48 @@ pub static BUTLER_PLUGIN_REGISTRY: LazyLock<ButlerPluginRegistry> = LazyLock::ne
 });
 
 pub trait ButlerPlugin: Plugin {
+    /// The `error_handler` of the plugin, used by entries that don't set their own
+    const ERROR_HANDLER: Option<super::ButlerErrorHandler> = None;
+
+    /// Applies the schedule build settings of the plugin to a schedule its entries add systems to
+    const CONFIGURE_SCHEDULE: Option<fn(&mut Schedule)> = None;
+
     fn register_butler_systems(app: &mut App, marker: TypeId) {
-        let factories = BUTLER_PLUGIN_REGISTRY.get_system_factories(marker);
-        for system_factory in factories {
-            system_factory(app);
-        }
-        bevy_log::debug!("{} ran {} factories", type_name::<Self>(), factories.len());
+        Self::run_butler_phase(app, marker, ButlerPhase::Build);
     }
-}
 
-#[cfg(not(any(target_arch = "wasm32", feature = "inventory")))]
-#[macro_export]
-#[doc(hidden)]
-macro_rules! _butler_plugin_entry {
-    ($static_ident:ident, $entry:expr) => {
-        #[::bevy_butler::__internal::linkme::distributed_slice(
-            ::bevy_butler::__internal::BUTLER_SLICE
-        )]
-        #[linkme(crate = ::bevy_butler::__internal::linkme)]
-        #[allow(non_upper_case_globals)]
-        static $static_ident: ::bevy_butler::__internal::ButlerPluginRegistryEntryFactory = $entry;
-    };
-}
+    fn run_butler_entries(app: &mut App, entries: &[&ButlerEntry]) {
+        for entry in entries {
+            let info = entry.info();
+            bevy_log::trace!(
+                "Running {} `{}` from {}:{}",
+                info.kind,
+                info.name,
+                info.file,
+                info.line
+            );
+            (entry.step())(app);
+            for schedule in entry.schedules() {
+                if let Some(configure) = Self::CONFIGURE_SCHEDULE {
+                    app.edit_schedule(schedule.label, configure);
+                }
+                crate::check::record_schedule_target(app, schedule, *info);
+            }
+        }
+    }
 
-#[cfg(any(target_arch = "wasm32", feature = "inventory"))]
-#[macro_export]
-#[doc(hidden)]
-macro_rules! _butler_plugin_entry {
-    ($static_ident:ident, $entry:expr) => {
-        ::bevy_butler::__internal::inventory::submit!($entry);
-    };
+    fn run_butler_phase(app: &mut App, marker: TypeId, phase: ButlerPhase) {
+        let entries = BUTLER_PLUGIN_REGISTRY.get_entries(marker, phase);
+        let (early, late) =
+            entries.split_at(entries.partition_point(|entry| entry.stage() < ButlerStage::Default));
+        Self::run_butler_entries(app, early);
+        // Assign the ids of the queued types before any other entry can use them
+        crate::component::register_pending_components(app);
+        Self::run_butler_entries(app, late);
+        bevy_log::debug!(
+            "{} ran {} factories ({phase:?})",
+            type_name::<Self>(),
+            entries.len()
+        );
+    }
 }
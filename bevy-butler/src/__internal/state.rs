@@ -0,0 +1,91 @@
+use bevy_diagnostic::FrameCount;
+use bevy_ecs::entity_disabling::Internal;
+use bevy_ecs::prelude::*;
+use bevy_ecs::query::Allow;
+use bevy_log::info;
+use bevy_state::state::{
+    ComputedStates, StateTransition, StateTransitionEvent, StateTransitionSystems, States,
+};
+
+use crate::state::{StateHistory, StateTransitionRecord};
+
+/// Fails to compile if `S` doesn't implement [`ComputedStates`].
+pub const fn assert_computed_states<S: ComputedStates>() {}
+
+/// Marks an observer entity spawned by `#[add_observer(scoped_to = ...)]`.
+///
+/// [`DespawnOnExit`](bevy_state::prelude::DespawnOnExit) can't be used here, since its
+/// cleanup system doesn't see [`Internal`] entities like observers.
+#[derive(Component)]
+pub struct ButlerScopedObserver<S: States>(pub S);
+
+type ScopedObservers<'w, 's, S> =
+    Query<'w, 's, (Entity, &'static ButlerScopedObserver<S>), Allow<Internal>>;
+
+/// Creates a system that despawns every observer scoped to `state`.
+pub fn despawn_scoped_observers<S: States>(
+    state: S,
+) -> impl FnMut(Commands, ScopedObservers<S>) {
+    move |mut commands, observers| {
+        for (entity, scope) in &observers {
+            if scope.0 == state {
+                commands.entity(entity).try_despawn();
+            }
+        }
+    }
+}
+
+/// Computed and sub states send a `None => None` transition when they are first
+/// evaluated without existing, which isn't worth logging or recording
+fn is_noop_transition<S: States>(transition: &StateTransitionEvent<S>) -> bool {
+    transition.exited.is_none() && transition.entered.is_none()
+}
+
+fn log_transitions_system<S: States>(mut transitions: MessageReader<StateTransitionEvent<S>>) {
+    for transition in transitions.read() {
+        if is_noop_transition(transition) {
+            continue;
+        }
+        info!(
+            "{} transition: {:?} => {:?}",
+            core::any::type_name::<S>(),
+            transition.exited,
+            transition.entered
+        );
+    }
+}
+
+/// Logs every transition of `S`, for `#[insert_state(log_transitions)]` and friends.
+pub fn log_state_transitions<S: States>(world: &mut World) {
+    world.resource_mut::<Schedules>().add_systems(
+        StateTransition,
+        log_transitions_system::<S>.after(StateTransitionSystems::EnterSchedules),
+    );
+}
+
+fn record_history_system<S: States>(
+    mut transitions: MessageReader<StateTransitionEvent<S>>,
+    mut history: ResMut<StateHistory<S>>,
+    frame: Option<Res<FrameCount>>,
+) {
+    let frame = frame.map_or(0, |frame| frame.0);
+    for transition in transitions.read() {
+        if is_noop_transition(transition) {
+            continue;
+        }
+        history.push(StateTransitionRecord {
+            exited: transition.exited.clone(),
+            entered: transition.entered.clone(),
+            frame,
+        });
+    }
+}
+
+/// Inserts a [`StateHistory<S>`] and keeps it updated, for `#[insert_state(history = N)]` and friends.
+pub fn record_state_history<S: States>(world: &mut World, capacity: usize) {
+    world.insert_resource(StateHistory::<S>::new(capacity));
+    world.resource_mut::<Schedules>().add_systems(
+        StateTransition,
+        record_history_system::<S>.after(StateTransitionSystems::EnterSchedules),
+    );
+}
@@ -0,0 +1,11 @@
+use bevy_app::{App, AppLabel, SubApp};
+
+/// Returns the [`SubApp`] with the given label for `#[butler_sub_app]`, inserting an empty one if it
+/// doesn't exist yet.
+pub fn init_sub_app(app: &mut App, label: impl AppLabel) -> &mut SubApp {
+    let label = label.intern();
+    if app.get_sub_app(label).is_none() {
+        app.insert_sub_app(label, SubApp::new());
+    }
+    app.sub_app_mut(label)
+}
@@ -0,0 +1,94 @@
+use std::future::Future;
+
+use bevy_ecs::error::{BevyError, HandleError};
+use bevy_ecs::prelude::*;
+use bevy_ecs::world::CommandQueue;
+use bevy_tasks::futures::check_ready;
+use bevy_tasks::{AsyncComputeTaskPool, Task};
+
+/// The output of an `#[add_system(async)]` future, applied to the world once it completes.
+///
+/// Errors are passed to the world's default error handler.
+pub trait ButlerAsyncOutput: Send + 'static {
+    fn into_commands(self) -> CommandQueue;
+}
+
+impl ButlerAsyncOutput for () {
+    fn into_commands(self) -> CommandQueue {
+        CommandQueue::default()
+    }
+}
+
+impl ButlerAsyncOutput for CommandQueue {
+    fn into_commands(self) -> CommandQueue {
+        self
+    }
+}
+
+impl<T: ButlerAsyncOutput, E: Into<BevyError> + Send + 'static> ButlerAsyncOutput for Result<T, E> {
+    fn into_commands(self) -> CommandQueue {
+        match self {
+            Ok(output) => output.into_commands(),
+            Err(error) => {
+                let error = error.into();
+                let mut commands = CommandQueue::default();
+                commands.push((move |_: &mut World| -> Result { Err(error) }).handle_error());
+                commands
+            }
+        }
+    }
+}
+
+/// A running `#[add_system(async)]` future, despawned once its output is applied.
+#[derive(Component)]
+pub struct ButlerAsyncTask(Task<CommandQueue>);
+
+fn spawn_task<Fut>(commands: &mut Commands, future: Fut)
+where
+    Fut: Future<Output: ButlerAsyncOutput> + Send + 'static,
+{
+    let task = AsyncComputeTaskPool::get().spawn(async move { future.await.into_commands() });
+    commands.spawn(ButlerAsyncTask(task));
+}
+
+/// The system spawning an `#[add_system(async)]` future on every run.
+pub fn spawn_async<F, Fut>(function: F) -> impl FnMut(Commands)
+where
+    F: Fn() -> Fut + Send + Sync + 'static,
+    Fut: Future<Output: ButlerAsyncOutput> + Send + 'static,
+{
+    move |mut commands: Commands| spawn_task(&mut commands, function())
+}
+
+/// The system spawning an `#[add_system(async)]` future on every run, with its piped input.
+pub fn spawn_async_with_input<F, I, Fut>(function: F) -> impl FnMut(In<I>, Commands)
+where
+    F: Fn(I) -> Fut + Send + Sync + 'static,
+    I: 'static,
+    Fut: Future<Output: ButlerAsyncOutput> + Send + 'static,
+{
+    move |In(input): In<I>, mut commands: Commands| spawn_task(&mut commands, function(input))
+}
+
+/// Applies and despawns every [`ButlerAsyncTask`] that has completed.
+pub fn poll_async_tasks(mut commands: Commands, mut tasks: Query<(Entity, &mut ButlerAsyncTask)>) {
+    for (entity, mut task) in &mut tasks {
+        if let Some(mut output) = check_ready(&mut task.0) {
+            commands.append(&mut output);
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Whether [`poll_async_tasks`] was already added
+#[derive(Resource)]
+struct ButlerAsyncPolling;
+
+/// Returns `true` the first time it's called for `world`, so [`poll_async_tasks`] is only added once.
+pub fn init_async_polling(world: &mut World) -> bool {
+    if world.contains_resource::<ButlerAsyncPolling>() {
+        return false;
+    }
+    world.insert_resource(ButlerAsyncPolling);
+    true
+}
@@ -0,0 +1,36 @@
+use std::time::Duration;
+
+use bevy_ecs::prelude::*;
+use bevy_time::{Time, Timer, TimerMode};
+
+use crate::timer::{ButlerTimer, ButlerTimers};
+
+/// Adds the timer of an `#[add_system(every = ...)]` entry, identified by the type `K` it generates.
+pub fn init_every_timer<K: 'static, F: 'static>(
+    world: &mut World,
+    _system: &F,
+    period: Duration,
+    initial_delay: Option<Duration>,
+) {
+    let timer = Timer::new(initial_delay.unwrap_or(period), TimerMode::Repeating);
+    world
+        .get_resource_or_init::<ButlerTimers>()
+        .insert::<K, F>(ButlerTimer::Every { timer, period });
+}
+
+/// Adds the timer of an `#[add_system(every_fixed_ticks = ...)]` entry, identified by the type `K` it generates.
+pub fn init_fixed_ticks_timer<K: 'static, F: 'static>(world: &mut World, _system: &F, period: u32) {
+    world
+        .get_resource_or_init::<ButlerTimers>()
+        .insert::<K, F>(ButlerTimer::FixedTicks { ticks: 0, period });
+}
+
+/// The system ticking the timer of the entry `K`, ordered before its system.
+pub fn tick_timer<K: 'static>() -> impl FnMut(Res<Time>, ResMut<ButlerTimers>) {
+    |time: Res<Time>, mut timers: ResMut<ButlerTimers>| timers.tick::<K>(time.delta())
+}
+
+/// The run condition of a periodic system, checking whether [`tick_timer`] just finished the timer of the entry `K`.
+pub fn timer_finished<K: 'static>() -> impl FnMut(Res<ButlerTimers>) -> bool {
+    |timers: Res<ButlerTimers>| timers.finished::<K>()
+}
@@ -0,0 +1,233 @@
+//! An opt-in check that every schedule targeted by a butler entry is actually run.
+//!
+//! Adding a system to a schedule creates it, so a system added to a custom schedule that nothing runs
+//! compiles fine and silently does nothing. [`ButlerScheduleCheckPlugin`] reports these entries on startup.
+//!
+//! ```rust,should_panic
+//! # use bevy::prelude::*;
+//! # use bevy_butler::*;
+//! # use bevy_ecs::schedule::ScheduleLabel;
+//! use bevy_butler::check::ButlerScheduleCheckPlugin;
+//!
+//! #[butler_plugin]
+//! struct MyPlugin;
+//!
+//! #[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+//! struct NetworkTick;
+//!
+//! // Nothing runs `NetworkTick`
+//! #[add_system(plugin = MyPlugin, schedule = NetworkTick)]
+//! fn send_packets() {}
+//!
+//! App::new()
+//!     .add_plugins((ButlerScheduleCheckPlugin::default(), MyPlugin))
+//!     .run(); // Panics
+//! ```
+use std::collections::HashSet;
+use std::fmt;
+
+use bevy_app::{
+    App, FixedMain, FixedMainScheduleOrder, Main, MainScheduleOrder, Plugin, PreStartup,
+    RunFixedMainLoop,
+};
+use bevy_ecs::prelude::*;
+use bevy_ecs::schedule::{InternedScheduleLabel, ScheduleLabel};
+use bevy_state::state::StateTransition;
+
+use crate::registry::{ButlerEntryInfo, ButlerSchedule};
+
+/// The schedules targeted by the butler entries that have run in this app.
+///
+/// Entries are only recorded while this resource exists, which [`ButlerScheduleCheckPlugin`] inserts.
+#[derive(Resource, Default, Debug)]
+pub struct ButlerScheduleTargets(Vec<(ButlerSchedule, ButlerEntryInfo)>);
+
+impl ButlerScheduleTargets {
+    /// Iterates over every targeted schedule, along with the entry targeting it.
+    pub fn iter(&self) -> impl Iterator<Item = &(ButlerSchedule, ButlerEntryInfo)> {
+        self.0.iter()
+    }
+}
+
+pub(crate) fn record_schedule_target(
+    app: &mut App,
+    schedule: ButlerSchedule,
+    info: ButlerEntryInfo,
+) {
+    if let Some(mut targets) = app.world_mut().get_resource_mut::<ButlerScheduleTargets>() {
+        targets.0.push((schedule, info));
+    }
+}
+
+/// Schedules that are run by user code rather than by [`Main`], and so are always considered reachable.
+#[derive(Resource, Default, Debug)]
+pub struct ManuallyRunSchedules(HashSet<InternedScheduleLabel>);
+
+impl ManuallyRunSchedules {
+    /// Marks a schedule as manually run.
+    pub fn insert(&mut self, label: impl ScheduleLabel) {
+        self.0.insert(label.intern());
+    }
+
+    /// Returns `true` if the schedule was marked as manually run.
+    pub fn contains(&self, label: impl ScheduleLabel) -> bool {
+        self.0.contains(&label.intern())
+    }
+}
+
+/// A butler entry targeting a schedule that is never run.
+#[derive(Clone, Debug)]
+pub struct UnreachableSchedule {
+    /// The schedule the entry adds systems to.
+    pub schedule: InternedScheduleLabel,
+    /// Where the entry came from.
+    pub info: ButlerEntryInfo,
+}
+
+/// The error returned by [`check_schedules`].
+#[derive(Clone, Debug)]
+pub struct UnreachableSchedules(pub Vec<UnreachableSchedule>);
+
+impl fmt::Display for UnreachableSchedules {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} butler entries target schedules that are never run by `Main`:",
+            self.0.len()
+        )?;
+        for UnreachableSchedule { schedule, info } in &self.0 {
+            write!(
+                f,
+                "\n  {} `{}` at {}:{} targets `{schedule:?}`",
+                info.kind, info.name, info.file, info.line
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for UnreachableSchedules {}
+
+/// Checks that every schedule targeted by a butler entry is run by [`Main`], through
+/// [`MainScheduleOrder`] or [`FixedMainScheduleOrder`], or is in [`ManuallyRunSchedules`].
+///
+/// State transition schedules are assumed to run as long as [`StateTransition`] does.
+/// Entries targeting a [`SubApp`](bevy_app::SubApp) aren't checked, and neither are entries that ran before
+/// [`ButlerScheduleTargets`] was inserted.
+///
+/// Must not be called from a system running in [`Main`], as the schedule orders are taken out of the world
+/// while it runs.
+pub fn check_schedules(world: &World) -> Result<(), UnreachableSchedules> {
+    check_against(world, &ordered_schedules(world))
+}
+
+/// Schedules run by [`Main`] through the schedule orders
+fn ordered_schedules(world: &World) -> HashSet<InternedScheduleLabel> {
+    let mut reachable: HashSet<InternedScheduleLabel> = HashSet::from([Main.intern()]);
+    if let Some(order) = world.get_resource::<MainScheduleOrder>() {
+        reachable.extend(order.startup_labels.iter().copied());
+        reachable.extend(order.labels.iter().copied());
+    }
+    if reachable.contains(&RunFixedMainLoop.intern()) {
+        reachable.insert(FixedMain.intern());
+        if let Some(order) = world.get_resource::<FixedMainScheduleOrder>() {
+            reachable.extend(order.labels.iter().copied());
+        }
+    }
+    reachable
+}
+
+fn check_against(
+    world: &World,
+    ordered: &HashSet<InternedScheduleLabel>,
+) -> Result<(), UnreachableSchedules> {
+    let Some(targets) = world.get_resource::<ButlerScheduleTargets>() else {
+        return Ok(());
+    };
+
+    let mut reachable = ordered.clone();
+    if let Some(manual) = world.get_resource::<ManuallyRunSchedules>() {
+        reachable.extend(manual.0.iter().copied());
+    }
+    let states_run = reachable.contains(&StateTransition.intern());
+
+    let unreachable: Vec<UnreachableSchedule> = targets
+        .iter()
+        .filter(|(schedule, _)| {
+            !(reachable.contains(&schedule.label) || states_run && schedule.state_transition)
+        })
+        .map(|(schedule, info)| UnreachableSchedule {
+            schedule: schedule.label,
+            info: *info,
+        })
+        .collect();
+
+    if unreachable.is_empty() {
+        Ok(())
+    } else {
+        Err(UnreachableSchedules(unreachable))
+    }
+}
+
+/// `Main` takes the schedule orders out of the world while it runs, so they're captured before startup
+#[derive(Resource)]
+struct OrderedSchedules(HashSet<InternedScheduleLabel>);
+
+/// Runs [`check_schedules`] on startup, panicking if any butler entry targets a schedule that never runs.
+///
+/// Only the entries of butler plugins added after this plugin are checked, so it should be added first.
+///
+/// The schedule orders are captured in [`Plugin::cleanup`], so the check is skipped if the app is
+/// updated manually without calling [`App::cleanup`] first.
+#[derive(Default)]
+pub struct ButlerScheduleCheckPlugin {
+    manual: Vec<InternedScheduleLabel>,
+    warn_only: bool,
+}
+
+impl ButlerScheduleCheckPlugin {
+    /// Marks a schedule as manually run, so entries targeting it aren't reported.
+    pub fn manually_run(mut self, label: impl ScheduleLabel) -> Self {
+        self.manual.push(label.intern());
+        self
+    }
+
+    /// Logs a warning instead of panicking.
+    pub fn warn_only(mut self) -> Self {
+        self.warn_only = true;
+        self
+    }
+}
+
+impl Plugin for ButlerScheduleCheckPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ButlerScheduleTargets>();
+        let mut manual = app
+            .world_mut()
+            .get_resource_or_init::<ManuallyRunSchedules>();
+        manual.0.extend(self.manual.iter().copied());
+
+        let warn_only = self.warn_only;
+        app.add_systems(PreStartup, move |world: &mut World| {
+            let Some(OrderedSchedules(ordered)) = world.remove_resource::<OrderedSchedules>()
+            else {
+                bevy_log::warn!(
+                    "ButlerScheduleCheckPlugin was skipped, as `App::cleanup` wasn't called before startup"
+                );
+                return;
+            };
+            if let Err(err) = check_against(world, &ordered) {
+                if warn_only {
+                    bevy_log::warn!("{err}");
+                } else {
+                    panic!("{err}");
+                }
+            }
+        });
+    }
+
+    fn cleanup(&self, app: &mut App) {
+        let ordered = ordered_schedules(app.world());
+        app.insert_resource(OrderedSchedules(ordered));
+    }
+}
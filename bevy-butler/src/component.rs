@@ -0,0 +1,110 @@
+//! Deterministic registration of the types annotated with [`register_component`](crate::register_component).
+//!
+//! A [`ComponentId`] is normally handed out the first time a type is used, which depends on the order systems
+//! are initialized and entries are linked in. Types registered through butler are instead registered in order of
+//! their type name before any other entry of a plugin's phase runs, so apps built from the same code and plugins
+//! always get the same ids. The result is listed in the [`ButlerComponentManifest`].
+//!
+//! ```rust
+//! # use bevy::prelude::*;
+//! # use bevy_butler::*;
+//! use bevy_butler::component::ButlerComponentManifest;
+//! # #[butler_plugin]
+//! # struct NetPlugin;
+//!
+//! #[derive(Component)]
+//! #[register_component(plugin = NetPlugin)]
+//! struct Position(Vec2);
+//!
+//! #[derive(Resource)]
+//! #[register_component(plugin = NetPlugin, resource)]
+//! struct Tick(u64);
+//!
+//! fn log_manifest(manifest: Res<ButlerComponentManifest>) {
+//!     for entry in manifest.iter() {
+//!         info!("{} => {:?}", entry.type_name, entry.id);
+//!     }
+//! }
+//! ```
+use bevy_app::App;
+use bevy_ecs::component::ComponentId;
+use bevy_ecs::prelude::*;
+
+/// A type registered by [`register_component`](crate::register_component).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ButlerComponentEntry {
+    /// The [`type_name`](std::any::type_name) of the registered type, which determines the registration order.
+    pub type_name: &'static str,
+    /// The id the type was registered with.
+    pub id: ComponentId,
+    /// Whether the type was registered as a [`Resource`] rather than a [`Component`].
+    pub resource: bool,
+}
+
+/// Every type registered by [`register_component`](crate::register_component) in this world, in registration order.
+#[derive(Resource, Default, Debug)]
+pub struct ButlerComponentManifest(Vec<ButlerComponentEntry>);
+
+impl ButlerComponentManifest {
+    /// Iterates over the registered types in registration order.
+    pub fn iter(&self) -> impl Iterator<Item = &ButlerComponentEntry> {
+        self.0.iter()
+    }
+
+    /// Returns the entry of the type with the given [`type_name`](std::any::type_name), if it was registered.
+    pub fn get(&self, type_name: &str) -> Option<&ButlerComponentEntry> {
+        self.0.iter().find(|entry| entry.type_name == type_name)
+    }
+
+    /// Returns the entry of `T`, if it was registered.
+    pub fn get_type<T: ?Sized>(&self) -> Option<&ButlerComponentEntry> {
+        self.get(std::any::type_name::<T>())
+    }
+
+    /// Returns the number of registered types.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if no types were registered.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+pub(crate) struct PendingComponent {
+    pub type_name: &'static str,
+    pub register: fn(&mut World) -> ComponentId,
+    pub resource: bool,
+}
+
+/// Types queued by the entries of the phase that is currently running
+#[derive(Resource, Default)]
+pub(crate) struct PendingComponents(pub Vec<PendingComponent>);
+
+/// Registers the types queued in every world of `app`, sorted by type name.
+pub(crate) fn register_pending_components(app: &mut App) {
+    for sub_app in app.sub_apps_mut().iter_mut() {
+        let world = sub_app.world_mut();
+        let Some(PendingComponents(mut pending)) = world.remove_resource::<PendingComponents>()
+        else {
+            continue;
+        };
+        pending.sort_by_key(|component| component.type_name);
+
+        let mut manifest = world
+            .remove_resource::<ButlerComponentManifest>()
+            .unwrap_or_default();
+        for component in pending {
+            if manifest.get(component.type_name).is_some() {
+                continue;
+            }
+            manifest.0.push(ButlerComponentEntry {
+                type_name: component.type_name,
+                id: (component.register)(world),
+                resource: component.resource,
+            });
+        }
+        world.insert_resource(manifest);
+    }
+}
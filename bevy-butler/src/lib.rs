@@ -3,6 +3,16 @@
 #[doc(hidden)]
 pub mod __internal;
 
+pub mod check;
+
+pub mod component;
+
+pub mod registry;
+
+pub mod state;
+
+pub mod timer;
+
 /// Configures a plugin to be usable within bevy_butler's various macros
 /// as a `plugin` argument.
 ///
@@ -17,7 +27,8 @@ pub mod __internal;
 ///
 /// ## On an `impl Plugin` block
 /// Annotating an `impl Plugin` block will transparently modify a user-defined [`Plugin`](bevy_app::prelude::Plugin) implementation
-/// to support usage with butler macros.
+/// to support usage with butler macros. Registration steps are inserted at the start of `build`, `finish` and `cleanup`,
+/// which are generated if they aren't already defined.
 /// ```rust
 /// # use bevy_app::prelude::*;
 /// # use bevy_butler::*;
@@ -30,8 +41,92 @@ pub mod __internal;
 ///     }
 /// }
 /// ```
+///
+/// To run the registration step somewhere else, place [`butler_register!`] where it should happen.
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_butler::*;
+/// struct MyPlugin;
+///
+/// #[derive(Resource)]
+/// struct Difficulty(u8);
+///
+/// #[butler_plugin]
+/// impl Plugin for MyPlugin {
+///     fn build(&self, app: &mut App) {
+///         app.insert_resource(Difficulty(3));
+///         // Butler entries can now rely on `Difficulty` existing
+///         butler_register!(app);
+///     }
+/// }
+/// ```
+///
+/// # Arguments
+/// ## `register`
+/// Where to insert the registration step into a user-defined `build`, `finish` or `cleanup`: `start` (the default) or `end`.
+/// Only valid on `impl Plugin` blocks, and can't be combined with [`butler_register!`].
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_butler::*;
+/// struct MyPlugin;
+///
+/// #[butler_plugin(register = end)]
+/// impl Plugin for MyPlugin {
+///     fn build(&self, app: &mut App) {
+///         /* Runs before any butler entries */
+///     }
+/// }
+/// ```
+///
+/// ## Schedule build settings
+/// `ambiguity_detection`, `hierarchy_detection`, `auto_insert_apply_deferred`, `use_shortnames` and `report_sets`
+/// override the matching [`ScheduleBuildSettings`](bevy_ecs::schedule::ScheduleBuildSettings) field of every schedule
+/// this plugin adds systems to. Schedules only used by other plugins, like [`Main`](bevy_app::Main), are left untouched.
+/// To configure a single schedule, use [`configure_schedule`].
+/// ```rust
+/// # use bevy_butler::*;
+/// #[butler_plugin(ambiguity_detection = error, hierarchy_detection = warn)]
+/// struct MyPlugin;
+/// ```
+///
+/// ## `error_handler`
+/// A `fn(BevyError, ErrorContext)` that receives the errors returned by every system and observer of this plugin,
+/// instead of the app's default error handler. Entries can override it with their own `error_handler`.
+///
+/// With Bevy's `debug` feature enabled, the name in the error context includes the plugin and where the entry
+/// was defined.
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::error::{BevyError, ErrorContext};
+/// # use bevy_butler::*;
+/// fn log_error(error: BevyError, context: ErrorContext) {
+///     error!("{context} failed: {error}");
+/// }
+///
+/// #[butler_plugin(error_handler = log_error)]
+/// struct MyPlugin;
+///
+/// #[add_system(plugin = MyPlugin, schedule = Update)]
+/// fn fallible() -> Result {
+///     Err("Something went wrong".into())
+/// }
+/// ```
 pub use bevy_butler_proc_macro::butler_plugin;
 
+/// Marks where butler entries are registered inside a [`#[butler_plugin]`](butler_plugin)-annotated
+/// `impl Plugin` block.
+///
+/// Must be a top-level statement of `build`, `finish` or `cleanup`, and may only appear once per function.
+/// It is replaced by [`#[butler_plugin]`](butler_plugin), so using it anywhere else is a compile error.
+#[macro_export]
+macro_rules! butler_register {
+    ($app:expr) => {
+        ::core::compile_error!(
+            "`butler_register!` must be a top-level statement of `build`, `finish` or `cleanup` in a `#[butler_plugin]` impl"
+        )
+    };
+}
+
 /// Registers a system to a [`#[butler_plugin]`](butler_plugin)-annotated [`Plugin`](bevy_app::prelude::Plugin).
 ///
 /// # Usage
@@ -62,12 +157,16 @@ pub use bevy_butler_proc_macro::butler_plugin;
 /// #[add_system(plugin = MyPlugin, schedule = Startup)]
 /// use my_mod::hello_world;
 /// ```
+/// ## On an associated function
+/// Associated functions are supported inside an `impl` block annotated with [`#[butler_impl]`](butler_impl).
+///
 /// # Arguments
 /// ## `plugin` (Required)
 /// A [`Plugin`](bevy_app::prelude::Plugin) annotated with [`#[butler_plugin]`](butler_plugin) to register this system to.
 ///
 /// ## `schedule` (Required)
 /// A [`Schedule`](bevy_ecs::prelude::Schedule) to run this system under.
+/// [`ButlerScheduleCheckPlugin`](check::ButlerScheduleCheckPlugin) can be used to verify that it actually runs.
 ///
 /// ## `generics`
 /// A list of generic arguments to register the system with. Used to register a generic system for multiple
@@ -118,6 +217,83 @@ pub use bevy_butler_proc_macro::butler_plugin;
 /// }
 /// ```
 ///
+/// ## `pipe_out` and `map`
+/// `map` passes the system's output through a function, and `pipe_out` pipes it into one or more systems, in the order given.
+/// `map` is applied first, so `map = f, pipe_out = [sys1, sys2]` would result in `<system>.map(f).pipe(sys1).pipe(sys2)`.
+///
+/// ```rust
+/// # use bevy_butler::*;
+/// # use bevy::prelude::*;
+/// # #[butler_plugin]
+/// # struct MyPlugin;
+/// fn report_errors(result: In<Result<usize>>) {
+///     if let Err(err) = result.0 {
+///         error!("Failed to spawn wave: {err}");
+///     }
+/// }
+///
+/// #[add_system(plugin = MyPlugin, schedule = Update, pipe_out = [report_errors])]
+/// fn spawn_wave() -> Result<usize> {
+///     Ok(10)
+/// }
+/// ```
+///
+/// ## `every`, `every_fixed_ticks` and `initial_delay`
+/// Runs the system periodically, through a run condition. `every` takes a [`Duration`](std::time::Duration)
+/// of [`Time`](bevy_time::Time), and `every_fixed_ticks` a number of runs of a fixed timestep schedule like `FixedUpdate`.
+/// With `every`, `initial_delay` sets how long to wait before the first run, instead of one period.
+///
+/// The timers are kept in the [`ButlerTimers`](timer::ButlerTimers) resource, where they can be inspected or reset.
+/// Each attribute has its own timer, so a function added to several schedules runs on a separate timer in each.
+/// ```rust
+/// # use std::time::Duration;
+/// # use bevy_butler::*;
+/// # use bevy::prelude::*;
+/// # #[butler_plugin]
+/// # struct MyPlugin;
+/// #[add_system(plugin = MyPlugin, schedule = Update, every = Duration::from_secs(60))]
+/// fn autosave() {
+///     info!("Saving...");
+/// }
+///
+/// #[add_system(plugin = MyPlugin, schedule = FixedUpdate, every_fixed_ticks = 10)]
+/// fn replan_ai() {
+///     info!("Replanning...");
+/// }
+/// ```
+///
+/// ## `async`
+/// Adds an `async fn` as a system that spawns its future on the [`AsyncComputeTaskPool`](bevy_tasks::AsyncComputeTaskPool)
+/// every time it runs. Once a future completes, its output is applied to the world from [`PreUpdate`](bevy_app::PreUpdate).
+/// The output can be `()`, a [`CommandQueue`](bevy_ecs::world::CommandQueue), or a `Result` of either, whose errors are
+/// passed to the default error handler.
+///
+/// Futures can't borrow from the world, so the function takes no system parameters.
+/// It can take a single argument instead, which is the output of `pipe_in`.
+/// ```rust
+/// # use bevy_butler::*;
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::world::CommandQueue;
+/// # #[butler_plugin]
+/// # struct MyPlugin;
+/// #[derive(Resource)]
+/// struct SaveFile(String);
+///
+/// fn save_path() -> String {
+///     "saves/slot1.sav".to_string()
+/// }
+///
+/// #[add_system(plugin = MyPlugin, schedule = Startup, pipe_in = [save_path], async)]
+/// async fn load_save(path: String) -> Result<CommandQueue> {
+///     let contents = format!("Contents of {path}");
+///     let mut commands = CommandQueue::default();
+///     commands.push(move |world: &mut World| {
+///         world.insert_resource(SaveFile(contents));
+///     });
+///     Ok(commands)
+/// }
+/// ```
+///
 /// ## System transforms
 /// Any attribute that doesn't match the above is assumed to be a system transform function, like [`run_if`](bevy_ecs::prelude::IntoScheduleConfigs::run_if)
 /// or [`after`](bevy_ecs::prelude::IntoScheduleConfigs::after).
@@ -145,8 +321,89 @@ pub use bevy_butler_proc_macro::butler_plugin;
 /// }
 /// ```
 ///
+/// ## `error_handler`
+/// A `fn(BevyError, ErrorContext)` that receives the errors returned by this system, overriding the
+/// plugin's [`error_handler`](butler_plugin#error_handler).
+/// ```rust
+/// # use bevy_butler::*;
+/// # use bevy::prelude::*;
+/// # #[butler_plugin]
+/// # struct MyPlugin;
+/// #[add_system(plugin = MyPlugin, schedule = Update, error_handler = bevy::ecs::error::warn)]
+/// fn fallible() -> Result {
+///     Err("Something went wrong".into())
+/// }
+/// ```
+///
+/// ## `sub_app`
+/// An [`AppLabel`](bevy_app::AppLabel) of a [`SubApp`](bevy_app::SubApp) to add this system to, instead of the main `App`.
+/// The sub app must already exist when the system is added, so it should be inserted with [`butler_sub_app`]
+/// in the same or an earlier [`phase`](#phase), or with [`App::insert_sub_app`](bevy_app::App::insert_sub_app)
+/// before the plugin is added.
+/// ```rust
+/// # use bevy_butler::*;
+/// # use bevy::prelude::*;
+/// # use bevy_app::AppLabel;
+/// # #[butler_plugin]
+/// # struct MyPlugin;
+/// #[derive(AppLabel, Debug, Clone, PartialEq, Eq, Hash)]
+/// #[butler_sub_app(plugin = MyPlugin)]
+/// struct SimulationApp;
+///
+/// #[add_system(plugin = MyPlugin, schedule = Update, sub_app = SimulationApp)]
+/// fn simulate() {
+///     info!("Simulating...");
+/// }
+/// ```
+///
+/// ## `phase`
+/// The [`Plugin`](bevy_app::prelude::Plugin) method to add this system from: `build` (the default), `finish` or `cleanup`.
+/// Entries in the `finish` phase run after every plugin has been built, so they can rely on resources inserted by other plugins.
+/// ```rust
+/// # use bevy_butler::*;
+/// # use bevy::prelude::*;
+/// # #[butler_plugin]
+/// # struct MyPlugin;
+/// #[add_system(plugin = MyPlugin, schedule = Update, phase = finish)]
+/// fn late_system() {
+///     info!("Added during `Plugin::finish`");
+/// }
+/// ```
+///
 pub use bevy_butler_proc_macro::add_system;
 
+/// Allows [`add_system`] on the associated functions of an inherent `impl` block.
+///
+/// The functions can't take `self`, and are registered as `Type::function`. Inside the block, `Self`
+/// can be used in the arguments of [`add_system`], like `plugin = Self` when the block belongs to the plugin itself.
+///
+/// # Usage
+/// ```rust
+/// # use bevy_butler::*;
+/// # use bevy::prelude::*;
+/// #[butler_plugin]
+/// struct AiPlugin;
+///
+/// #[butler_impl]
+/// impl AiPlugin {
+///     #[add_system(plugin = Self, schedule = Startup)]
+///     fn setup() {
+///         info!("Setting up AI");
+///     }
+/// }
+///
+/// struct EnemyAi;
+///
+/// #[butler_impl]
+/// impl EnemyAi {
+///     #[add_system(plugin = AiPlugin, schedule = Update, after = AiPlugin::setup)]
+///     fn think() {
+///         info!("Thinking...");
+///     }
+/// }
+/// ```
+pub use bevy_butler_proc_macro::butler_impl;
+
 /// Registers an [observer](bevy_ecs::prelude::Observer) function to a [`#[butler_plugin]`](butler_plugin)-annotated [`Plugin`](bevy_app::prelude::Plugin).
 ///
 /// # Usage
@@ -198,6 +455,128 @@ pub use bevy_butler_proc_macro::add_system;
 /// ## `generics`
 /// A list of generic arguments to register the observer with. Used to register a generic observer for multiple
 /// different types.
+///
+/// ## `scoped_to`
+/// Instead of adding the observer once when the plugin is built, spawn its observer entity every time the given
+/// state is entered, and despawn it again when the state is exited.
+/// ```rust
+/// # use bevy_butler::*;
+/// # use bevy::prelude::*;
+/// # #[butler_plugin]
+/// # struct MyPlugin;
+/// # #[derive(Event)]
+/// # struct Jump;
+/// #[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
+/// #[insert_state(plugin = MyPlugin)]
+/// enum GameState {
+///     #[default]
+///     Menu,
+///     Playing,
+/// }
+///
+/// #[add_observer(plugin = MyPlugin, scoped_to = GameState::Playing)]
+/// fn on_jump(_jump: On<Jump>) {
+///     info!("Jumped!");
+/// }
+/// ```
+///
+/// ## `watch`
+/// Instead of observing every entity, attach a separate observer to each entity that gains the given
+/// [`Component`](bevy_ecs::prelude::Component), and detach it again when the component is removed.
+/// ```rust
+/// # use bevy_butler::*;
+/// # use bevy::prelude::*;
+/// # #[butler_plugin]
+/// # struct MyPlugin;
+/// # #[derive(EntityEvent)]
+/// # struct Damage { entity: Entity }
+/// #[derive(Component)]
+/// struct Enemy;
+///
+/// #[add_observer(plugin = MyPlugin, watch = Enemy)]
+/// fn enemy_hit(damage: On<Damage>) {
+///     info!("Enemy {} was hit", damage.entity);
+/// }
+/// ```
+///
+/// ## `entities`
+/// Instead of observing every entity, only observe the entities returned by the given function or closure.
+/// It is called with the `&mut World` in [`PostStartup`](bevy_app::PostStartup), so it can use the entities
+/// spawned by [`Startup`](bevy_app::Startup) systems, and must return an iterator of [`Entity`](bevy_ecs::prelude::Entity).
+/// ```rust
+/// # use bevy_butler::*;
+/// # use bevy::prelude::*;
+/// # #[butler_plugin]
+/// # struct MyPlugin;
+/// # #[derive(EntityEvent)]
+/// # struct Damage { entity: Entity }
+/// #[derive(Resource)]
+/// struct Boss(Entity);
+///
+/// #[add_system(plugin = MyPlugin, schedule = Startup)]
+/// fn spawn_boss(mut commands: Commands) {
+///     let boss = commands.spawn_empty().id();
+///     commands.insert_resource(Boss(boss));
+/// }
+///
+/// #[add_observer(plugin = MyPlugin, entities = |world: &mut World| [world.resource::<Boss>().0])]
+/// fn boss_hit(damage: On<Damage>) {
+///     info!("The boss was hit");
+/// }
+/// ```
+///
+/// `scoped_to`, `watch` and `entities` can't be combined.
+///
+/// ## `name`
+/// A [`Name`](bevy_ecs::name::Name) to insert on the spawned observer entity.
+///
+/// ## `components`
+/// A component, or an array of components, to insert on the spawned observer entity, so it can be found or
+/// despawned later.
+///
+/// ## Observer transforms
+/// Any attribute that doesn't match the above is assumed to be a method of [`Observer`](bevy_ecs::prelude::Observer),
+/// like [`with_error_handler`](bevy_ecs::prelude::Observer::with_error_handler), and is applied in the same styles as
+/// the system transforms of [`add_system`].
+///
+/// `run_if` is handled separately: since observers don't support run conditions, the observer is wrapped so it's
+/// skipped whenever the condition returns `false`.
+/// ```rust
+/// # use bevy_butler::*;
+/// # use bevy::prelude::*;
+/// # #[butler_plugin]
+/// # struct MyPlugin;
+/// # #[derive(Event)]
+/// # struct Jump;
+/// # #[derive(Resource)]
+/// # struct Grounded;
+/// #[derive(Component)]
+/// struct JumpObserver;
+///
+/// #[add_observer(
+///     plugin = MyPlugin,
+///     run_if = resource_exists::<Grounded>,
+///     with_error_handler = bevy::ecs::error::warn,
+///     name = "on_jump",
+///     components = JumpObserver,
+/// )]
+/// fn on_jump(_jump: On<Jump>) -> Result {
+///     info!("Jumped!");
+///     Ok(())
+/// }
+/// ```
+///
+/// ## `error_handler`
+/// A `fn(BevyError, ErrorContext)` that receives the errors returned by this observer, overriding the
+/// plugin's [`error_handler`](butler_plugin#error_handler). Unlike `with_error_handler`, the name in the error context
+/// includes the plugin and where the observer was defined.
+///
+/// ## `sub_app`
+/// An [`AppLabel`](bevy_app::AppLabel) of a [`SubApp`](bevy_app::SubApp) to register this observer to, instead of the main `App`.
+/// See [`butler_sub_app`] for configuring the sub app.
+///
+/// ## `phase`
+/// The [`Plugin`](bevy_app::prelude::Plugin) method to register this observer from: `build` (the default), `finish` or `cleanup`.
 pub use bevy_butler_proc_macro::add_observer;
 
 /// Registers the annotated [`Resource`](bevy_ecs::prelude::Resource) to a [`#[butler_plugin]`](butler_plugin) and
@@ -285,6 +664,34 @@ pub use bevy_butler_proc_macro::add_observer;
 /// #[insert_resource(plugin = MyPlugin, non_send)]
 /// struct MyNonSendResource;
 /// ```
+///
+/// ## `scoped_to`
+/// Instead of inserting the resource once when the plugin is built, insert it every time the given state is entered,
+/// and remove it when that state is exited. The resource is created from `init` (or its [`Default`] value) on every entry.
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_butler::*;
+/// # #[butler_plugin]
+/// # struct MyPlugin;
+/// #[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
+/// #[insert_state(plugin = MyPlugin)]
+/// enum GameState {
+///     #[default]
+///     Menu,
+///     Playing,
+/// }
+///
+/// #[derive(Resource, Default)]
+/// #[insert_resource(plugin = MyPlugin, scoped_to = GameState::Playing)]
+/// struct Score(u32);
+/// ```
+///
+/// ## `sub_app`
+/// An [`AppLabel`](bevy_app::AppLabel) of a [`SubApp`](bevy_app::SubApp) to register this resource to, instead of the main `App`.
+/// See [`butler_sub_app`] for configuring the sub app.
+///
+/// ## `phase`
+/// The [`Plugin`](bevy_app::prelude::Plugin) method to register this resource from: `build` (the default), `finish` or `cleanup`.
 pub use bevy_butler_proc_macro::insert_resource;
 
 /// Registers the annotated [`Message`](bevy_ecs::prelude::Message) upon the
@@ -337,6 +744,13 @@ pub use bevy_butler_proc_macro::insert_resource;
 /// ## `generics`
 /// A list of generic arguments to register the message with. Used to register a generic message for multiple
 /// different types.
+///
+/// ## `sub_app`
+/// An [`AppLabel`](bevy_app::AppLabel) of a [`SubApp`](bevy_app::SubApp) to register this message to, instead of the main `App`.
+/// See [`butler_sub_app`] for configuring the sub app.
+///
+/// ## `phase`
+/// The [`Plugin`](bevy_app::prelude::Plugin) method to register this message from: `build` (the default), `finish` or `cleanup`.
 pub use bevy_butler_proc_macro::add_message;
 
 /// Registers the annotated `Reflect` type into the app's type registry for reflection.
@@ -385,6 +799,13 @@ pub use bevy_butler_proc_macro::add_message;
 /// ## `generics`
 /// A list of generic arguments to register the reflect type with. Used to register a generic reflect type for multiple
 /// different types.
+///
+/// ## `sub_app`
+/// An [`AppLabel`](bevy_app::AppLabel) of a [`SubApp`](bevy_app::SubApp) to register this type to, instead of the main `App`.
+/// See [`butler_sub_app`] for configuring the sub app.
+///
+/// ## `phase`
+/// The [`Plugin`](bevy_app::prelude::Plugin) method to register this type from: `build` (the default), `finish` or `cleanup`.
 pub use bevy_butler_proc_macro::register_type;
 
 /// Implements `PluginGroup` and configures it to be used with [`add_plugin`]/[`add_plugin_group`].
@@ -544,6 +965,37 @@ pub use bevy_butler_proc_macro::add_plugin_group;
 /// 
 /// ## `generics`
 /// A list of generic arguments to register the state with. Used to register a generic state for multiple different types.
+/// 
+/// ## `sub_app`
+/// An [`AppLabel`](bevy_app::AppLabel) of a [`SubApp`](bevy_app::SubApp) to register this state to, instead of the main `App`.
+/// See [`butler_sub_app`] for configuring the sub app.
+/// 
+/// ## `phase`
+/// The [`Plugin`](bevy_app::prelude::Plugin) method to register this state from: `build` (the default), `finish` or `cleanup`.
+/// 
+/// ## `log_transitions`
+/// Logs every transition of this state at the `info` level.
+/// 
+/// ## `reflect`
+/// Registers the state, its [`State`](bevy_state::state::State) and its [`NextState`](bevy_state::state::NextState)
+/// resources with the type registry. The state must implement [`Reflect`](bevy_ecs::reflect).
+/// 
+/// ## `history`
+/// Inserts a [`StateHistory`](state::StateHistory) resource that keeps the last `history` transitions of this state,
+/// along with the frame they happened on.
+/// ```rust
+/// # use bevy_butler::*;
+/// # use bevy::prelude::*;
+/// # #[butler_plugin]
+/// # struct GamePlugin;
+/// #[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash, Reflect)]
+/// #[insert_state(plugin = GamePlugin, log_transitions, reflect, history = 16)]
+/// enum GameState {
+///     #[default]
+///     Menu,
+///     InGame,
+/// }
+/// ```
 pub use bevy_butler_proc_macro::insert_state;
 
 /// Adds the annotated sub state to a `#[butler_plugin]`
@@ -607,8 +1059,954 @@ pub use bevy_butler_proc_macro::insert_state;
 /// 
 /// ## `generics`
 /// A list of generic arguments to register the sub state with. Used to register a generic sub state for multiple different types.
+/// 
+/// ## `sub_app`
+/// An [`AppLabel`](bevy_app::AppLabel) of a [`SubApp`](bevy_app::SubApp) to register this sub state to, instead of the main `App`.
+/// See [`butler_sub_app`] for configuring the sub app.
+/// 
+/// ## `phase`
+/// The [`Plugin`](bevy_app::prelude::Plugin) method to register this sub state from: `build` (the default), `finish` or `cleanup`.
+/// 
+/// ## `log_transitions`, `reflect` and `history`
+/// Debugging options for this sub state. See [`insert_state`](insert_state#log_transitions).
 pub use bevy_butler_proc_macro::add_sub_state;
 
+/// Adds the annotated computed state to a `#[butler_plugin]`
+/// 
+/// The annotated type must implement [`ComputedStates`](bevy_state::state::ComputedStates),
+/// otherwise a compile error is raised on the type.
+/// 
+/// # Usage
+/// ## On a struct
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_butler::*;
+/// # #[butler_plugin]
+/// # struct GamePlugin;
+/// #[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
+/// #[insert_state(plugin = GamePlugin)]
+/// enum GameState {
+///     #[default]
+///     Loading,
+///     InGame { paused: bool }
+/// }
+/// 
+/// #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// #[add_computed_state(plugin = GamePlugin)]
+/// struct InGame;
+/// 
+/// impl ComputedStates for InGame {
+///     type SourceStates = GameState;
+/// 
+///     fn compute(sources: GameState) -> Option<Self> {
+///         matches!(sources, GameState::InGame { .. }).then_some(InGame)
+///     }
+/// }
+/// ```
+/// 
+/// ## On a use statement
+/// ```rust
+/// # use bevy_butler::*;
+/// # #[butler_plugin]
+/// # struct GamePlugin;
+/// # mod my_mod {
+/// #   use bevy::prelude::*;
+/// #   #[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
+/// #   pub enum GameState {
+/// #       #[default]
+/// #       Loading,
+/// #       InGame
+/// #   }
+/// #   #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// #   pub struct InGame;
+/// #   impl ComputedStates for InGame {
+/// #       type SourceStates = GameState;
+/// #       fn compute(sources: GameState) -> Option<Self> {
+/// #           (sources == GameState::InGame).then_some(InGame)
+/// #       }
+/// #   }
+/// # }
+/// #[insert_state(plugin = GamePlugin)]
+/// use my_mod::GameState;
+/// #[add_computed_state(plugin = GamePlugin)]
+/// use my_mod::InGame;
+/// ```
+/// 
+/// # Arguments
+/// 
+/// ## `plugin` (Required)
+/// A [`Plugin`](bevy_app::prelude::Plugin) annotated with [`#[butler_plugin]`](butler_plugin) to register this computed state to.
+/// 
+/// ## `generics`
+/// A list of generic arguments to register the computed state with. Used to register a generic computed state for multiple different types.
+/// 
+/// ## `sub_app`
+/// An [`AppLabel`](bevy_app::AppLabel) of a [`SubApp`](bevy_app::SubApp) to register this computed state to, instead of the main `App`.
+/// See [`butler_sub_app`] for configuring the sub app.
+///
+/// ## `phase`
+/// The [`Plugin`](bevy_app::prelude::Plugin) method to register this computed state from: `build` (the default), `finish` or `cleanup`.
+///
+/// ## `log_transitions`, `reflect` and `history`
+/// Debugging options for this computed state. See [`insert_state`](insert_state#log_transitions).
+/// As computed states can't be set directly, `reflect` doesn't register a [`NextState`](bevy_state::state::NextState).
+pub use bevy_butler_proc_macro::add_computed_state;
+
+/// Adds the annotated system to the [`OnEnter`](bevy_state::prelude::OnEnter) schedule of one or more states
+/// of a [`#[butler_plugin]`](butler_plugin).
+///
+/// This is shorthand for `#[add_system(schedule = OnEnter(...))]`, and accepts the same
+/// transforms, `generics`, `pipe_in`, `error_handler`, `sub_app` and `phase` arguments as [`add_system`].
+///
+/// # Usage
+/// ```rust
+/// # use bevy_butler::*;
+/// # use bevy::prelude::*;
+/// # #[butler_plugin]
+/// # struct GamePlugin;
+/// #[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
+/// #[insert_state(plugin = GamePlugin)]
+/// enum GameState {
+///     #[default]
+///     Menu,
+///     Paused,
+///     InGame,
+/// }
+///
+/// #[on_enter(plugin = GamePlugin, state = GameState::InGame)]
+/// fn start_game() {
+///     info!("Game started!");
+/// }
+///
+/// #[on_enter(plugin = GamePlugin, state = [GameState::Menu, GameState::Paused], after = start_game)]
+/// fn show_menu() {
+///     info!("Showing menu");
+/// }
+/// ```
+///
+/// # Arguments
+///
+/// ## `plugin` (Required)
+/// A [`Plugin`](bevy_app::prelude::Plugin) annotated with [`#[butler_plugin]`](butler_plugin) to register this system to.
+///
+/// ## `state` (Required)
+/// The state value to run the system on entering, or an array of state values.
+/// The system is added once for every state in the array.
+pub use bevy_butler_proc_macro::on_enter;
+
+/// Adds the annotated system to the [`OnExit`](bevy_state::prelude::OnExit) schedule of one or more states
+/// of a [`#[butler_plugin]`](butler_plugin).
+///
+/// This is shorthand for `#[add_system(schedule = OnExit(...))]`, and accepts the same
+/// transforms, `generics`, `pipe_in`, `error_handler`, `sub_app` and `phase` arguments as [`add_system`].
+///
+/// # Usage
+/// ```rust
+/// # use bevy_butler::*;
+/// # use bevy::prelude::*;
+/// # #[butler_plugin]
+/// # struct GamePlugin;
+/// #[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
+/// #[insert_state(plugin = GamePlugin)]
+/// enum GameState {
+///     #[default]
+///     Menu,
+///     InGame,
+/// }
+///
+/// #[on_exit(plugin = GamePlugin, state = GameState::InGame)]
+/// fn save_game() {
+///     info!("Saving...");
+/// }
+/// ```
+///
+/// # Arguments
+///
+/// ## `plugin` (Required)
+/// A [`Plugin`](bevy_app::prelude::Plugin) annotated with [`#[butler_plugin]`](butler_plugin) to register this system to.
+///
+/// ## `state` (Required)
+/// The state value to run the system on exiting, or an array of state values.
+/// The system is added once for every state in the array.
+pub use bevy_butler_proc_macro::on_exit;
+
+/// Adds the annotated system to the [`OnTransition`](bevy_state::prelude::OnTransition) schedule
+/// between two states of a [`#[butler_plugin]`](butler_plugin).
+///
+/// This is shorthand for `#[add_system(schedule = OnTransition { exited: ..., entered: ... })]`, and accepts the same
+/// transforms, `generics`, `pipe_in`, `error_handler`, `sub_app` and `phase` arguments as [`add_system`].
+///
+/// # Usage
+/// ```rust
+/// # use bevy_butler::*;
+/// # use bevy::prelude::*;
+/// # #[butler_plugin]
+/// # struct GamePlugin;
+/// #[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
+/// #[insert_state(plugin = GamePlugin)]
+/// enum GameState {
+///     #[default]
+///     Menu,
+///     InGame,
+/// }
+///
+/// #[on_transition(plugin = GamePlugin, from = GameState::Menu, to = GameState::InGame)]
+/// fn leave_menu() {
+///     info!("Leaving the menu");
+/// }
+/// ```
+///
+/// # Arguments
+///
+/// ## `plugin` (Required)
+/// A [`Plugin`](bevy_app::prelude::Plugin) annotated with [`#[butler_plugin]`](butler_plugin) to register this system to.
+///
+/// ## `from` (Required)
+/// The state being exited.
+///
+/// ## `to` (Required)
+/// The state being entered.
+pub use bevy_butler_proc_macro::on_transition;
+
+/// Configures the annotated [`SystemSet`](bevy_ecs::prelude::SystemSet) in a schedule of a [`#[butler_plugin]`](butler_plugin),
+/// using [`configure_sets`](bevy_app::prelude::App::configure_sets).
+///
+/// On an enum, every variant is configured as a set. Individual variants can be configured further with
+/// a `#[butler(...)]` attribute, which accepts the same set transforms as `add_system_set`.
+///
+/// # Usage
+/// ## On an enum
+/// ```rust
+/// # use bevy_butler::*;
+/// # use bevy::prelude::*;
+/// # #[butler_plugin]
+/// # struct MyPlugin;
+/// #[add_system_set(plugin = MyPlugin, schedule = Update, chain)]
+/// #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+/// enum GameSet {
+///     Input,
+///     Physics,
+///     Render,
+/// }
+///
+/// #[add_system(plugin = MyPlugin, schedule = Update, in_set = GameSet::Input)]
+/// fn read_input() {}
+/// ```
+///
+/// ## On a unit struct
+/// ```rust
+/// # use bevy_butler::*;
+/// # use bevy::prelude::*;
+/// # #[butler_plugin]
+/// # struct MyPlugin;
+/// #[derive(Resource)]
+/// struct Paused(bool);
+///
+/// #[add_system_set(plugin = MyPlugin, schedule = Update, run_if = |paused: Res<Paused>| !paused.0)]
+/// #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+/// struct GameplaySet;
+/// ```
+///
+/// # Arguments
+/// ## `plugin` (Required)
+/// A [`Plugin`](bevy_app::prelude::Plugin) annotated with [`#[butler_plugin]`](butler_plugin) to register this set to.
+///
+/// ## `schedule` (Required)
+/// A [`Schedule`](bevy_ecs::prelude::Schedule) to configure this set in.
+///
+/// ## Set transforms
+/// Any attribute that doesn't match the above is assumed to be a set transform function, like
+/// [`chain`](bevy_ecs::prelude::IntoScheduleConfigs::chain), [`run_if`](bevy_ecs::prelude::IntoScheduleConfigs::run_if)
+/// or [`before`](bevy_ecs::prelude::IntoScheduleConfigs::before). On an enum, they apply to all of its variants,
+/// and `chain` orders the variants in declaration order.
+/// ```rust
+/// # use bevy_butler::*;
+/// # use bevy::prelude::*;
+/// # #[butler_plugin]
+/// # struct MyPlugin;
+/// #[add_system_set(plugin = MyPlugin, schedule = Update)]
+/// #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+/// enum GameSet {
+///     Input,
+///     #[butler(after = GameSet::Input)]
+///     Physics,
+///     #[butler(after = GameSet::Input, run_if = || false)]
+///     Debug,
+/// }
+/// ```
+///
+/// ## `sub_app`
+/// An [`AppLabel`](bevy_app::AppLabel) of a [`SubApp`](bevy_app::SubApp) to configure this set in, instead of the main `App`.
+/// See [`butler_sub_app`] for configuring the sub app.
+///
+/// ## `phase`
+/// The [`Plugin`](bevy_app::prelude::Plugin) method to configure this set from: `build` (the default), `finish` or `cleanup`.
+pub use bevy_butler_proc_macro::add_system_set;
+
+/// Adds the annotated [`ScheduleLabel`](bevy_ecs::schedule::ScheduleLabel) as a schedule of a [`#[butler_plugin]`](butler_plugin),
+/// optionally inserting it into the [`Main`](bevy_app::prelude::Main) schedule order.
+///
+/// # Usage
+/// ## On a unit struct
+/// ```rust
+/// # use bevy_butler::*;
+/// # use bevy::prelude::*;
+/// # use bevy_ecs::schedule::ScheduleLabel;
+/// # #[butler_plugin]
+/// # struct MyPlugin;
+/// #[add_schedule(plugin = MyPlugin, after = Update, executor = SingleThreaded)]
+/// #[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+/// struct NetworkTick;
+///
+/// #[add_system(plugin = MyPlugin, schedule = NetworkTick)]
+/// fn send_packets() {}
+/// ```
+///
+/// ## On an imported type
+/// ```rust
+/// # use bevy_butler::*;
+/// # #[butler_plugin]
+/// # struct MyPlugin;
+/// # mod my_mod {
+/// #   use bevy_ecs::schedule::ScheduleLabel;
+/// #   #[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+/// #   pub struct AiPlanning;
+/// # }
+/// #[add_schedule(plugin = MyPlugin, fixed, after = bevy::prelude::FixedUpdate)]
+/// use my_mod::AiPlanning;
+/// ```
+///
+/// # Arguments
+/// ## `plugin` (Required)
+/// A [`Plugin`](bevy_app::prelude::Plugin) annotated with [`#[butler_plugin]`](butler_plugin) to add this schedule to.
+///
+/// ## `label`
+/// The label of the schedule. Defaults to the annotated type, which must be a unit struct, an imported type or a type alias.
+///
+/// ## `after` / `before`
+/// Inserts the schedule into [`MainScheduleOrder`](bevy_app::MainScheduleOrder) after or before the given schedule,
+/// so it runs every frame. The given schedule must already be in the order, so a schedule added with `add_schedule`
+/// should only be used as a position from a later [`phase`](#phase). Without either, the schedule is only initialized.
+///
+/// Panics if the app has no schedule order, like an app without the main schedule.
+///
+/// ## `startup`
+/// Inserts the schedule into the startup schedules of [`MainScheduleOrder`](bevy_app::MainScheduleOrder) instead,
+/// so it only runs once.
+///
+/// ## `fixed`
+/// Inserts the schedule into [`FixedMainScheduleOrder`](bevy_app::FixedMainScheduleOrder) instead,
+/// so it runs in the fixed timestep loop.
+///
+/// ## `manual`
+/// Marks the schedule as run by user code, so [`ButlerScheduleCheckPlugin`](check::ButlerScheduleCheckPlugin)
+/// doesn't report systems added to it. Can't be combined with `after` or `before`.
+///
+/// ## `executor`
+/// The [`ExecutorKind`](bevy_ecs::schedule::ExecutorKind) of the schedule, like `SingleThreaded` or `MultiThreaded`.
+///
+/// ## `build_settings`
+/// The [`ScheduleBuildSettings`](bevy_ecs::schedule::ScheduleBuildSettings) of the schedule.
+///
+/// ## `sub_app`
+/// An [`AppLabel`](bevy_app::AppLabel) of a [`SubApp`](bevy_app::SubApp) to add this schedule to, instead of the main `App`.
+/// Can't be combined with `after` or `before`, as only the main `App` has a schedule order.
+/// See [`butler_sub_app`] for configuring the sub app.
+///
+/// ## `phase`
+/// The [`Plugin`](bevy_app::prelude::Plugin) method to add this schedule from: `build` (the default), `finish` or `cleanup`.
+pub use bevy_butler_proc_macro::add_schedule;
+
+/// Overrides the [`ScheduleBuildSettings`](bevy_ecs::schedule::ScheduleBuildSettings) of a schedule from a [`#[butler_plugin]`](butler_plugin).
+///
+/// Only the given settings are changed. To change them for every schedule, pass them to [`butler_plugin`] instead.
+///
+/// # Usage
+/// ## On a schedule label
+/// ```rust
+/// # use bevy_butler::*;
+/// # use bevy_ecs::schedule::ScheduleLabel;
+/// # #[butler_plugin]
+/// # struct MyPlugin;
+/// #[configure_schedule(plugin = MyPlugin, ambiguity_detection = error)]
+/// #[add_schedule(plugin = MyPlugin)]
+/// #[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+/// struct NetworkTick;
+/// ```
+///
+/// ## On an imported schedule label
+/// ```rust
+/// # use bevy_butler::*;
+/// # #[butler_plugin]
+/// # struct MyPlugin;
+/// #[configure_schedule(plugin = MyPlugin, ambiguity_detection = warn, auto_insert_apply_deferred = false)]
+/// use bevy::prelude::Update;
+/// ```
+///
+/// # Arguments
+/// ## `plugin` (Required)
+/// A [`Plugin`](bevy_app::prelude::Plugin) annotated with [`#[butler_plugin]`](butler_plugin) to configure this schedule from.
+///
+/// ## `label`
+/// The label of the schedule. Defaults to the annotated type, which must be a unit struct, an imported type or a type alias.
+///
+/// ## `ambiguity_detection` / `hierarchy_detection`
+/// The [`LogLevel`](bevy_ecs::schedule::LogLevel) to report ambiguities or redundant hierarchy edges with:
+/// `ignore`, `warn` or `error`.
+///
+/// ## `auto_insert_apply_deferred` / `use_shortnames` / `report_sets`
+/// Overrides the matching `bool` setting.
+///
+/// ## `sub_app`
+/// An [`AppLabel`](bevy_app::AppLabel) of a [`SubApp`](bevy_app::SubApp) to configure this schedule in, instead of the main `App`.
+/// See [`butler_sub_app`] for configuring the sub app.
+///
+/// ## `phase`
+/// The [`Plugin`](bevy_app::prelude::Plugin) method to configure this schedule from: `build` (the default), `finish` or `cleanup`.
+pub use bevy_butler_proc_macro::configure_schedule;
+
+/// Ignores system order ambiguities on the annotated [`Resource`](bevy_ecs::prelude::Resource) in a [`#[butler_plugin]`](butler_plugin),
+/// using [`allow_ambiguous_resource`](bevy_app::prelude::App::allow_ambiguous_resource).
+///
+/// # Usage
+/// ```rust
+/// # use bevy_butler::*;
+/// # use bevy::prelude::*;
+/// # #[butler_plugin]
+/// # struct MyPlugin;
+/// #[derive(Resource, Default)]
+/// #[insert_resource(plugin = MyPlugin)]
+/// #[allow_ambiguous_resource(plugin = MyPlugin)]
+/// struct DebugCounter(u32);
+/// ```
+///
+/// # Arguments
+/// ## `plugin` (Required)
+/// A [`Plugin`](bevy_app::prelude::Plugin) annotated with [`#[butler_plugin]`](butler_plugin) to register this to.
+///
+/// ## `generics`
+/// A list of generic arguments of the resource.
+///
+/// ## `sub_app`
+/// An [`AppLabel`](bevy_app::AppLabel) of a [`SubApp`](bevy_app::SubApp) to register this to, instead of the main `App`.
+///
+/// ## `phase`
+/// The [`Plugin`](bevy_app::prelude::Plugin) method to register this from: `build` (the default), `finish` or `cleanup`.
+pub use bevy_butler_proc_macro::allow_ambiguous_resource;
+
+/// Ignores system order ambiguities on the annotated [`Component`](bevy_ecs::prelude::Component) in a [`#[butler_plugin]`](butler_plugin),
+/// using [`allow_ambiguous_component`](bevy_app::prelude::App::allow_ambiguous_component).
+///
+/// # Usage
+/// ```rust
+/// # use bevy_butler::*;
+/// # use bevy::prelude::*;
+/// # #[butler_plugin]
+/// # struct MyPlugin;
+/// #[derive(Component)]
+/// #[allow_ambiguous_component(plugin = MyPlugin)]
+/// struct Highlighted;
+/// ```
+///
+/// # Arguments
+/// ## `plugin` (Required)
+/// A [`Plugin`](bevy_app::prelude::Plugin) annotated with [`#[butler_plugin]`](butler_plugin) to register this to.
+///
+/// ## `generics`
+/// A list of generic arguments of the component.
+///
+/// ## `sub_app`
+/// An [`AppLabel`](bevy_app::AppLabel) of a [`SubApp`](bevy_app::SubApp) to register this to, instead of the main `App`.
+///
+/// ## `phase`
+/// The [`Plugin`](bevy_app::prelude::Plugin) method to register this from: `build` (the default), `finish` or `cleanup`.
+pub use bevy_butler_proc_macro::allow_ambiguous_component;
+
+/// Ignores system order ambiguities between the annotated system or system set and others,
+/// using [`ignore_ambiguity`](bevy_app::prelude::App::ignore_ambiguity).
+///
+/// Enum system sets can't be annotated, since each variant is its own set. Use a variant in the
+/// `with` of another system or set instead.
+///
+/// # Usage
+/// ```rust
+/// # use bevy_butler::*;
+/// # use bevy::prelude::*;
+/// # #[butler_plugin]
+/// # struct MyPlugin;
+/// # #[derive(Resource, Default)]
+/// # struct Score(u32);
+/// #[add_system(plugin = MyPlugin, schedule = Update)]
+/// fn add_score(mut score: ResMut<Score>) {
+///     score.0 += 1;
+/// }
+///
+/// #[add_system(plugin = MyPlugin, schedule = Update)]
+/// #[ignore_ambiguity(plugin = MyPlugin, schedule = Update, with = add_score)]
+/// fn double_score(mut score: ResMut<Score>) {
+///     score.0 *= 2;
+/// }
+/// ```
+///
+/// # Arguments
+/// ## `plugin` (Required)
+/// A [`Plugin`](bevy_app::prelude::Plugin) annotated with [`#[butler_plugin]`](butler_plugin) to register this to.
+///
+/// ## `schedule` (Required)
+/// The schedule the ambiguity is in.
+///
+/// ## `with` (Required)
+/// The system or system set the annotated item is ambiguous with, or an array of them.
+///
+/// ## `sub_app`
+/// An [`AppLabel`](bevy_app::AppLabel) of a [`SubApp`](bevy_app::SubApp) to register this to, instead of the main `App`.
+///
+/// ## `phase`
+/// The [`Plugin`](bevy_app::prelude::Plugin) method to register this from: `build` (the default), `finish` or `cleanup`.
+pub use bevy_butler_proc_macro::ignore_ambiguity;
+
+/// Registers the annotated function as a [one-shot system](bevy_ecs::system::SystemId) when a
+/// [`#[butler_plugin]`](butler_plugin) is built, and stores its [`SystemId`](bevy_ecs::system::SystemId)
+/// in a generated [`Resource`](bevy_ecs::prelude::Resource) handle.
+///
+/// The handle is named after the function in `UpperCamelCase`, has the same visibility, and wraps a
+/// `SystemId<I, O>` matching the function's [`In`](bevy_ecs::prelude::In) parameter and return type.
+///
+/// # Usage
+/// ```rust
+/// # use bevy_butler::*;
+/// # use bevy::prelude::*;
+/// # #[butler_plugin]
+/// # struct MyPlugin;
+/// #[register_system(plugin = MyPlugin)]
+/// fn spawn_wave(In(size): In<u32>) {
+///     info!("Spawning {size} enemies");
+/// }
+///
+/// #[add_system(plugin = MyPlugin, schedule = Update)]
+/// fn start_level(mut commands: Commands, spawn: Res<SpawnWave>) {
+///     commands.run_system_with(spawn.0, 10);
+/// }
+/// ```
+///
+/// # Arguments
+/// ## `plugin` (Required)
+/// A [`Plugin`](bevy_app::prelude::Plugin) annotated with [`#[butler_plugin]`](butler_plugin) to register this system to.
+///
+/// ## `handle`
+/// The name of the generated handle, instead of the function's name in `UpperCamelCase`.
+///
+/// ## `sub_app`
+/// An [`AppLabel`](bevy_app::AppLabel) of a [`SubApp`](bevy_app::SubApp) to register this system to, instead of the main `App`.
+/// See [`butler_sub_app`] for configuring the sub app.
+///
+/// ## `phase`
+/// The [`Plugin`](bevy_app::prelude::Plugin) method to register this system from: `build` (the default), `finish` or `cleanup`.
+pub use bevy_butler_proc_macro::register_system;
+
+/// Sets the annotated function as the [`on_add`](bevy_ecs::lifecycle::ComponentHooks::on_add) hook of a component when a
+/// [`#[butler_plugin]`](butler_plugin) is built. The hook runs whenever the component is added to an entity that didn't have it.
+///
+/// Unlike `#[component(on_add = ...)]`, this works on components defined in other crates.
+/// Panics when the plugin is built if the component already has an `on_add` hook, naming whichever
+/// butler function set it first.
+///
+/// # Usage
+/// ```rust
+/// # use bevy_butler::*;
+/// # use bevy::prelude::*;
+/// # use bevy_ecs::{lifecycle::HookContext, world::DeferredWorld};
+/// # #[butler_plugin]
+/// # struct MyPlugin;
+/// #[on_add(plugin = MyPlugin, component = Transform)]
+/// fn log_spawn(world: DeferredWorld, context: HookContext) {
+///     let transform = world.get::<Transform>(context.entity).unwrap();
+///     info!("Spawned at {}", transform.translation);
+/// }
+/// ```
+///
+/// # Arguments
+/// ## `plugin` (Required)
+/// A [`Plugin`](bevy_app::prelude::Plugin) annotated with [`#[butler_plugin]`](butler_plugin) to register this hook to.
+///
+/// ## `component` (Required)
+/// The [`Component`](bevy_ecs::prelude::Component) to set the hook of.
+///
+/// ## `sub_app`
+/// An [`AppLabel`](bevy_app::AppLabel) of a [`SubApp`](bevy_app::SubApp) to register this hook to, instead of the main `App`.
+/// See [`butler_sub_app`] for configuring the sub app.
+///
+/// ## `phase`
+/// The [`Plugin`](bevy_app::prelude::Plugin) method to register this hook from: `build` (the default), `finish` or `cleanup`.
+pub use bevy_butler_proc_macro::on_add;
+
+/// Sets the annotated function as the [`on_insert`](bevy_ecs::lifecycle::ComponentHooks::on_insert) hook of a component when a
+/// [`#[butler_plugin]`](butler_plugin) is built. The hook runs whenever the component is inserted into an entity, whether or not it already had it.
+///
+/// Unlike `#[component(on_insert = ...)]`, this works on components defined in other crates.
+/// Panics when the plugin is built if the component already has an `on_insert` hook, naming whichever
+/// butler function set it first.
+///
+/// # Usage
+/// ```rust
+/// # use bevy_butler::*;
+/// # use bevy::prelude::*;
+/// # use bevy_ecs::{lifecycle::HookContext, world::DeferredWorld};
+/// # #[butler_plugin]
+/// # struct MyPlugin;
+/// #[on_insert(plugin = MyPlugin, component = Name)]
+/// fn log_rename(world: DeferredWorld, context: HookContext) {
+///     info!("Named {}", world.get::<Name>(context.entity).unwrap());
+/// }
+/// ```
+///
+/// # Arguments
+/// ## `plugin` (Required)
+/// A [`Plugin`](bevy_app::prelude::Plugin) annotated with [`#[butler_plugin]`](butler_plugin) to register this hook to.
+///
+/// ## `component` (Required)
+/// The [`Component`](bevy_ecs::prelude::Component) to set the hook of.
+///
+/// ## `sub_app`
+/// An [`AppLabel`](bevy_app::AppLabel) of a [`SubApp`](bevy_app::SubApp) to register this hook to, instead of the main `App`.
+/// See [`butler_sub_app`] for configuring the sub app.
+///
+/// ## `phase`
+/// The [`Plugin`](bevy_app::prelude::Plugin) method to register this hook from: `build` (the default), `finish` or `cleanup`.
+pub use bevy_butler_proc_macro::on_insert;
+
+/// Sets the annotated function as the [`on_replace`](bevy_ecs::lifecycle::ComponentHooks::on_replace) hook of a component when a
+/// [`#[butler_plugin]`](butler_plugin) is built. The hook runs whenever the component is about to be replaced or removed, while its old value is still present.
+///
+/// Unlike `#[component(on_replace = ...)]`, this works on components defined in other crates.
+/// Panics when the plugin is built if the component already has an `on_replace` hook, naming whichever
+/// butler function set it first.
+///
+/// # Usage
+/// ```rust
+/// # use bevy_butler::*;
+/// # use bevy::prelude::*;
+/// # use bevy_ecs::{lifecycle::HookContext, world::DeferredWorld};
+/// # #[butler_plugin]
+/// # struct MyPlugin;
+/// #[on_replace(plugin = MyPlugin, component = Name)]
+/// fn log_old_name(world: DeferredWorld, context: HookContext) {
+///     info!("No longer named {}", world.get::<Name>(context.entity).unwrap());
+/// }
+/// ```
+///
+/// # Arguments
+/// ## `plugin` (Required)
+/// A [`Plugin`](bevy_app::prelude::Plugin) annotated with [`#[butler_plugin]`](butler_plugin) to register this hook to.
+///
+/// ## `component` (Required)
+/// The [`Component`](bevy_ecs::prelude::Component) to set the hook of.
+///
+/// ## `sub_app`
+/// An [`AppLabel`](bevy_app::AppLabel) of a [`SubApp`](bevy_app::SubApp) to register this hook to, instead of the main `App`.
+/// See [`butler_sub_app`] for configuring the sub app.
+///
+/// ## `phase`
+/// The [`Plugin`](bevy_app::prelude::Plugin) method to register this hook from: `build` (the default), `finish` or `cleanup`.
+pub use bevy_butler_proc_macro::on_replace;
+
+/// Sets the annotated function as the [`on_remove`](bevy_ecs::lifecycle::ComponentHooks::on_remove) hook of a component when a
+/// [`#[butler_plugin]`](butler_plugin) is built. The hook runs whenever the component is about to be removed from an entity.
+///
+/// Unlike `#[component(on_remove = ...)]`, this works on components defined in other crates.
+/// Panics when the plugin is built if the component already has an `on_remove` hook, naming whichever
+/// butler function set it first.
+///
+/// # Usage
+/// ```rust
+/// # use bevy_butler::*;
+/// # use bevy::prelude::*;
+/// # use bevy_ecs::{lifecycle::HookContext, world::DeferredWorld};
+/// # #[butler_plugin]
+/// # struct MyPlugin;
+/// #[on_remove(plugin = MyPlugin, component = Name)]
+/// fn log_unnamed(_world: DeferredWorld, context: HookContext) {
+///     info!("{} lost its name", context.entity);
+/// }
+/// ```
+///
+/// # Arguments
+/// ## `plugin` (Required)
+/// A [`Plugin`](bevy_app::prelude::Plugin) annotated with [`#[butler_plugin]`](butler_plugin) to register this hook to.
+///
+/// ## `component` (Required)
+/// The [`Component`](bevy_ecs::prelude::Component) to set the hook of.
+///
+/// ## `sub_app`
+/// An [`AppLabel`](bevy_app::AppLabel) of a [`SubApp`](bevy_app::SubApp) to register this hook to, instead of the main `App`.
+/// See [`butler_sub_app`] for configuring the sub app.
+///
+/// ## `phase`
+/// The [`Plugin`](bevy_app::prelude::Plugin) method to register this hook from: `build` (the default), `finish` or `cleanup`.
+pub use bevy_butler_proc_macro::on_remove;
+
+/// Sets the annotated function as the [`on_despawn`](bevy_ecs::lifecycle::ComponentHooks::on_despawn) hook of a component when a
+/// [`#[butler_plugin]`](butler_plugin) is built. The hook runs whenever the component is about to be despawned along with its entity.
+///
+/// Unlike `#[component(on_despawn = ...)]`, this works on components defined in other crates.
+/// Panics when the plugin is built if the component already has an `on_despawn` hook, naming whichever
+/// butler function set it first.
+///
+/// # Usage
+/// ```rust
+/// # use bevy_butler::*;
+/// # use bevy::prelude::*;
+/// # use bevy_ecs::{lifecycle::HookContext, world::DeferredWorld};
+/// # #[butler_plugin]
+/// # struct MyPlugin;
+/// #[on_despawn(plugin = MyPlugin, component = Transform)]
+/// fn log_despawn(_world: DeferredWorld, context: HookContext) {
+///     info!("{} despawned", context.entity);
+/// }
+/// ```
+///
+/// # Arguments
+/// ## `plugin` (Required)
+/// A [`Plugin`](bevy_app::prelude::Plugin) annotated with [`#[butler_plugin]`](butler_plugin) to register this hook to.
+///
+/// ## `component` (Required)
+/// The [`Component`](bevy_ecs::prelude::Component) to set the hook of.
+///
+/// ## `sub_app`
+/// An [`AppLabel`](bevy_app::AppLabel) of a [`SubApp`](bevy_app::SubApp) to register this hook to, instead of the main `App`.
+/// See [`butler_sub_app`] for configuring the sub app.
+///
+/// ## `phase`
+/// The [`Plugin`](bevy_app::prelude::Plugin) method to register this hook from: `build` (the default), `finish` or `cleanup`.
+pub use bevy_butler_proc_macro::on_despawn;
+
+/// Registers [required components](bevy_ecs::prelude::Component#required-components) for the annotated
+/// [`Component`](bevy_ecs::prelude::Component) when a [`#[butler_plugin]`](butler_plugin) is built.
+///
+/// Unlike `#[require(...)]`, this works on components defined in other crates.
+///
+/// # Usage
+/// ## On an imported component
+/// ```rust
+/// # use bevy_butler::*;
+/// # use bevy::prelude::*;
+/// # #[butler_plugin]
+/// # struct MyPlugin;
+/// # mod physics {
+/// #     use bevy::prelude::*;
+/// #     #[derive(Component)]
+/// #     pub struct RigidBody;
+/// # }
+/// #[register_required_components(plugin = MyPlugin, requires = [Transform, Name])]
+/// use physics::RigidBody;
+/// ```
+/// ## With a constructor
+/// ```rust
+/// # use bevy_butler::*;
+/// # use bevy::prelude::*;
+/// # #[butler_plugin]
+/// # struct MyPlugin;
+/// # #[derive(Component)]
+/// # struct Enemy;
+/// #[register_required_components(plugin = MyPlugin, requires = Name, constructor = || Name::new("Enemy"))]
+/// type EnemyMarker = Enemy;
+/// ```
+///
+/// # Arguments
+/// ## `plugin` (Required)
+/// A [`Plugin`](bevy_app::prelude::Plugin) annotated with [`#[butler_plugin]`](butler_plugin) to register the requirements to.
+///
+/// ## `requires` (Required)
+/// A required component, or an array of them. Each is constructed with [`Default`] unless `constructor` is given.
+///
+/// ## `constructor`
+/// A function or non-capturing closure returning the required component. Only valid with a single required component.
+///
+/// ## `sub_app`
+/// An [`AppLabel`](bevy_app::AppLabel) of a [`SubApp`](bevy_app::SubApp) to register the requirements to, instead of the main `App`.
+/// See [`butler_sub_app`] for configuring the sub app.
+///
+/// ## `phase`
+/// The [`Plugin`](bevy_app::prelude::Plugin) method to register the requirements from: `build` (the default), `finish` or `cleanup`.
+pub use bevy_butler_proc_macro::register_required_components;
+
+/// Registers the annotated [`Component`](bevy_ecs::prelude::Component) (or [`Resource`](bevy_ecs::prelude::Resource))
+/// with the [`World`](bevy_ecs::prelude::World) when a [`#[butler_plugin]`](butler_plugin) is built.
+///
+/// The types are registered in order of their type name after every other entry of the plugin's phase has run,
+/// so their [`ComponentId`](bevy_ecs::component::ComponentId)s don't depend on link or first-use order.
+/// The resulting ids are listed in the [`ButlerComponentManifest`](component::ButlerComponentManifest).
+/// See the [`component`] module for more information.
+///
+/// # Usage
+/// ## On a component
+/// ```rust
+/// # use bevy_butler::*;
+/// # use bevy::prelude::*;
+/// # #[butler_plugin]
+/// # struct NetPlugin;
+/// #[derive(Component)]
+/// #[register_component(plugin = NetPlugin)]
+/// struct Position(Vec2);
+/// ```
+/// ## On an imported resource
+/// ```rust
+/// # use bevy_butler::*;
+/// # use bevy::prelude::*;
+/// # #[butler_plugin]
+/// # struct NetPlugin;
+/// #[register_component(plugin = NetPlugin, resource)]
+/// use bevy::time::Time;
+/// ```
+///
+/// # Arguments
+/// ## `plugin` (Required)
+/// A [`Plugin`](bevy_app::prelude::Plugin) annotated with [`#[butler_plugin]`](butler_plugin) to register this type to.
+///
+/// ## `resource`
+/// Registers the type as a [`Resource`](bevy_ecs::prelude::Resource) instead of a [`Component`](bevy_ecs::prelude::Component).
+///
+/// ## `sub_app`
+/// An [`AppLabel`](bevy_app::AppLabel) of a [`SubApp`](bevy_app::SubApp) to register this type to, instead of the main `App`.
+/// See [`butler_sub_app`] for configuring the sub app.
+///
+/// ## `phase`
+/// The [`Plugin`](bevy_app::prelude::Plugin) method to register this type from: `build` (the default), `finish` or `cleanup`.
+pub use bevy_butler_proc_macro::register_component;
+
+/// Inserts the annotated [`AppLabel`](bevy_app::AppLabel) as a [`SubApp`](bevy_app::SubApp) when the
+/// given [`#[butler_plugin]`](butler_plugin) is built.
+///
+/// Other butler macros can then target the sub app through their `sub_app` argument. The sub app is inserted
+/// before the other entries of its [`phase`](#phase) run, so they can target it from the same or a later phase.
+/// If it already exists, it's configured instead.
+///
+/// # Usage
+/// ## On a struct
+/// ```rust
+/// # use bevy_butler::*;
+/// # use bevy::prelude::*;
+/// # use bevy_app::AppLabel;
+/// # #[butler_plugin]
+/// # struct MyPlugin;
+/// #[derive(AppLabel, Debug, Clone, PartialEq, Eq, Hash)]
+/// #[butler_sub_app(plugin = MyPlugin, extract = extract_score, update_schedule = Update)]
+/// struct SimulationApp;
+///
+/// #[derive(Resource, Default)]
+/// #[insert_resource(plugin = MyPlugin)]
+/// #[insert_resource(plugin = MyPlugin, sub_app = SimulationApp)]
+/// struct Score(u32);
+///
+/// fn extract_score(main_world: &mut World, sim_world: &mut World) {
+///     sim_world.resource_mut::<Score>().0 = main_world.resource::<Score>().0;
+/// }
+/// ```
+///
+/// # Arguments
+/// ## `plugin` (Required)
+/// A [`Plugin`](bevy_app::prelude::Plugin) annotated with [`#[butler_plugin]`](butler_plugin) that inserts this sub app.
+///
+/// ## `label`
+/// The label value to insert the sub app under. Defaults to the annotated type itself, which requires it to be
+/// a unit struct.
+///
+/// ## `extract`
+/// A function with the signature `fn(&mut World, &mut World)`, passed to [`set_extract`](bevy_app::SubApp::set_extract).
+///
+/// ## `update_schedule`
+/// The schedule to run whenever the sub app is [updated](bevy_app::SubApp::update).
+///
+/// ## `phase`
+/// The [`Plugin`](bevy_app::prelude::Plugin) method to register this sub app from: `build` (the default), `finish` or `cleanup`.
+pub use bevy_butler_proc_macro::butler_sub_app;
+
+/// Runs the annotated function from [`Plugin::build`](bevy_app::prelude::Plugin::build) of a [`#[butler_plugin]`](butler_plugin).
+///
+/// This is an escape hatch for any `App` setup that doesn't have a dedicated butler macro,
+/// like [`configure_sets`](bevy_app::prelude::App::configure_sets) or third-party `App` extension traits,
+/// so it can live next to the code it configures.
+///
+/// # Usage
+/// ## On a free-standing function
+/// ```rust
+/// # use bevy_butler::*;
+/// # use bevy::prelude::*;
+/// # #[butler_plugin]
+/// # struct MyPlugin;
+/// #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+/// enum GameSet {
+///     Input,
+///     Movement,
+/// }
+///
+/// #[butler_build(plugin = MyPlugin)]
+/// fn configure_game_sets(app: &mut App) {
+///     app.configure_sets(Update, (GameSet::Input, GameSet::Movement).chain());
+/// }
+/// ```
+///
+/// ## On an imported function
+/// ```rust
+/// # use bevy_butler::*;
+/// # #[butler_plugin]
+/// # struct MyPlugin;
+/// # mod my_mod {
+/// #   use bevy::prelude::*;
+/// #   pub fn setup(app: &mut App) {}
+/// # }
+/// #[butler_build(plugin = MyPlugin)]
+/// use my_mod::setup;
+/// ```
+///
+/// # Arguments
+/// ## `plugin` (Required)
+/// A [`Plugin`](bevy_app::prelude::Plugin) annotated with [`#[butler_plugin]`](butler_plugin) to run this function from.
+pub use bevy_butler_proc_macro::butler_build;
+
+/// Runs the annotated function from [`Plugin::finish`](bevy_app::prelude::Plugin::finish) of a [`#[butler_plugin]`](butler_plugin).
+///
+/// `finish` runs after every plugin has been built, so the function can rely on resources
+/// inserted by other plugins.
+///
+/// # Usage
+/// ## On a free-standing function
+/// ```rust
+/// # use bevy_butler::*;
+/// # use bevy::prelude::*;
+/// # #[butler_plugin]
+/// # struct MyPlugin;
+/// #[butler_finish(plugin = MyPlugin)]
+/// fn check_assets(app: &mut App) {
+///     info!("Finishing with {} entities", app.world().entities().len());
+/// }
+/// ```
+///
+/// # Arguments
+/// ## `plugin` (Required)
+/// A [`Plugin`](bevy_app::prelude::Plugin) annotated with [`#[butler_plugin]`](butler_plugin) to run this function from.
+pub use bevy_butler_proc_macro::butler_finish;
+
+/// Runs the annotated function from [`Plugin::cleanup`](bevy_app::prelude::Plugin::cleanup) of a [`#[butler_plugin]`](butler_plugin).
+///
+/// # Usage
+/// ## On a free-standing function
+/// ```rust
+/// # use bevy_butler::*;
+/// # use bevy::prelude::*;
+/// # #[butler_plugin]
+/// # struct MyPlugin;
+/// #[butler_cleanup(plugin = MyPlugin)]
+/// fn cleanup(app: &mut App) {
+///     info!("Cleaning up");
+/// }
+/// ```
+///
+/// # Arguments
+/// ## `plugin` (Required)
+/// A [`Plugin`](bevy_app::prelude::Plugin) annotated with [`#[butler_plugin]`](butler_plugin) to run this function from.
+pub use bevy_butler_proc_macro::butler_cleanup;
+
 #[cfg(all(target_arch = "wasm32", not(feature = "wasm-experimental")))]
 compile_error!(
     "WebAssembly support in bevy-butler is experimental and buggy.
@@ -0,0 +1,285 @@
+//! Stable building blocks for registering entries into a [`#[butler_plugin]`](crate::butler_plugin).
+//!
+//! Every butler macro expands to a static [`ButlerEntry`] submitted with [`butler_entry!`](crate::butler_entry).
+//! When a butler plugin runs one of its [`ButlerPhase`]s, it runs every entry submitted for it.
+//!
+//! Third-party crates can define their own registration macros on top of this module.
+//! The `bevy-butler-codegen` crate generates entries from within a proc macro, and should be
+//! preferred over writing the expansion by hand.
+//!
+//! ```rust
+//! # use bevy_butler::*;
+//! # use bevy_butler::registry::*;
+//! # use bevy_app::prelude::*;
+//! #[butler_plugin]
+//! struct MyPlugin;
+//!
+//! bevy_butler::butler_entry!(
+//!     _my_entry,
+//!     ButlerEntry::new(
+//!         MyPlugin::butler_plugin_marker,
+//!         |app| { app.add_systems(Update, || {}); },
+//!     )
+//!     .in_phase(ButlerPhase::Finish)
+//! );
+//! ```
+use std::any::TypeId;
+
+use bevy_app::{App, AppLabel, SubApp};
+use bevy_ecs::schedule::{InternedScheduleLabel, ScheduleLabel};
+use bevy_state::state::{OnEnter, OnExit, OnTransition, States};
+
+pub use bevy_butler_codegen::ButlerPhase;
+
+/// Identifies the entries of a plugin, implemented by every [`#[butler_plugin]`](crate::butler_plugin).
+pub trait ButlerPluginMarker {
+    /// The [`TypeId`] entries submitted for this plugin are looked up by.
+    ///
+    /// It's the id of a type private to the generated implementation, so no other plugin can share it.
+    fn butler_plugin_marker() -> TypeId;
+}
+
+/// Describes where an entry came from, for logging and diagnostics.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ButlerEntryInfo {
+    /// The kind of entry, usually the name of the macro that generated it.
+    pub kind: &'static str,
+    /// A human-readable name for the registered item.
+    pub name: &'static str,
+    /// The file the entry was declared in.
+    pub file: &'static str,
+    /// The line the entry was declared on.
+    pub line: u32,
+}
+
+impl ButlerEntryInfo {
+    /// Placeholder info for entries that didn't provide any.
+    pub const UNKNOWN: Self = ButlerEntryInfo {
+        kind: "unknown",
+        name: "unknown",
+        file: "unknown",
+        line: 0,
+    };
+}
+
+/// When an entry runs within its phase. Entries of a phase run stage by stage, in the order of the variants.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ButlerStage {
+    /// Inserts sub apps, so every other entry of the phase can target them.
+    InsertSubApps,
+    /// Runs before the default stage, for entries whose effect must not depend on the order entries
+    /// are linked in. The types queued by [`register_component`](crate::register_component) are
+    /// registered once this stage has run.
+    Early,
+    /// Where entries run unless stated otherwise.
+    #[default]
+    Default,
+}
+
+/// A schedule an entry adds systems to, see [`ButlerEntry::with_schedules`].
+///
+/// Macros can build it with [`ButlerScheduleProbe`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ButlerSchedule {
+    /// The label of the schedule.
+    pub label: InternedScheduleLabel,
+    /// Whether this is an [`OnEnter`], [`OnExit`] or [`OnTransition`] schedule, run by
+    /// [`StateTransition`](bevy_state::state::StateTransition) rather than through the schedule order.
+    pub state_transition: bool,
+}
+
+/// Builds the [`ButlerSchedule`] of a schedule label whose type is known where it's written, like in the
+/// expansion of a macro.
+///
+/// [`OnEnter`], [`OnExit`] and [`OnTransition`] get an inherent `schedule` method marking them as state
+/// transition schedules, which takes priority over [`ButlerScheduleProbeFallback::schedule`] for them.
+/// The fallback trait must be in scope:
+///
+/// ```rust
+/// # use bevy_app::prelude::*;
+/// # use bevy_state::prelude::*;
+/// use bevy_butler::registry::{ButlerScheduleProbe, ButlerScheduleProbeFallback as _};
+///
+/// #[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
+/// enum GameState {
+///     #[default]
+///     Menu,
+/// }
+///
+/// assert!(!ButlerScheduleProbe(&Update).schedule().state_transition);
+/// assert!(ButlerScheduleProbe(&OnEnter(GameState::Menu)).schedule().state_transition);
+/// ```
+pub struct ButlerScheduleProbe<'a, L>(pub &'a L);
+
+/// The `schedule` method of [`ButlerScheduleProbe`] for every label that isn't a state transition schedule.
+pub trait ButlerScheduleProbeFallback {
+    /// Builds the [`ButlerSchedule`] of a label that isn't a state transition schedule.
+    fn schedule(&self) -> ButlerSchedule;
+}
+
+impl<L: ScheduleLabel> ButlerScheduleProbeFallback for ButlerScheduleProbe<'_, L> {
+    fn schedule(&self) -> ButlerSchedule {
+        ButlerSchedule {
+            label: self.0.intern(),
+            state_transition: false,
+        }
+    }
+}
+
+macro_rules! state_transition_probe {
+    ($($schedule:ident),*) => {$(
+        impl<S: States> ButlerScheduleProbe<'_, $schedule<S>> {
+            #[doc = concat!("Builds the [`ButlerSchedule`] of a [`", stringify!($schedule), "`] schedule.")]
+            pub fn schedule(&self) -> ButlerSchedule {
+                ButlerSchedule {
+                    label: self.0.intern(),
+                    state_transition: true,
+                }
+            }
+        }
+    )*};
+}
+
+state_transition_probe!(OnEnter, OnExit, OnTransition);
+
+/// A single registration step for a [`#[butler_plugin]`](crate::butler_plugin).
+pub struct ButlerEntry {
+    plugin_marker: fn() -> TypeId,
+    step: fn(&mut App),
+    phase: ButlerPhase,
+    info: ButlerEntryInfo,
+    schedules: &'static [fn() -> ButlerSchedule],
+    stage: ButlerStage,
+}
+
+impl ButlerEntry {
+    /// Creates an entry that runs `step` when the plugin identified by `plugin_marker` is built.
+    ///
+    /// `plugin_marker` should be the plugin's [`ButlerPluginMarker::butler_plugin_marker`].
+    pub const fn new(plugin_marker: fn() -> TypeId, step: fn(&mut App)) -> Self {
+        ButlerEntry {
+            plugin_marker,
+            step,
+            phase: ButlerPhase::Build,
+            info: ButlerEntryInfo::UNKNOWN,
+            schedules: &[],
+            stage: ButlerStage::Default,
+        }
+    }
+
+    /// Runs this entry in the given phase instead of [`ButlerPhase::Build`].
+    pub const fn in_phase(mut self, phase: ButlerPhase) -> Self {
+        self.phase = phase;
+        self
+    }
+
+    /// Attaches information about where this entry came from.
+    pub const fn with_info(mut self, info: ButlerEntryInfo) -> Self {
+        self.info = info;
+        self
+    }
+
+    /// Declares the schedules this entry adds systems to, so the plugin's build settings are applied to them
+    /// and [`check_schedules`](crate::check::check_schedules) can verify that they run.
+    pub const fn with_schedules(mut self, schedules: &'static [fn() -> ButlerSchedule]) -> Self {
+        self.schedules = schedules;
+        self
+    }
+
+    /// Runs this entry in the given stage of its phase instead of [`ButlerStage::Default`].
+    pub const fn in_stage(mut self, stage: ButlerStage) -> Self {
+        self.stage = stage;
+        self
+    }
+
+    /// The [`TypeId`] of the plugin's [`ButlerPluginMarker`].
+    pub fn plugin_marker(&self) -> TypeId {
+        (self.plugin_marker)()
+    }
+
+    /// The registration step.
+    pub fn step(&self) -> fn(&mut App) {
+        self.step
+    }
+
+    /// The phase this entry runs in.
+    pub fn phase(&self) -> ButlerPhase {
+        self.phase
+    }
+
+    /// Information about where this entry came from.
+    pub fn info(&self) -> &ButlerEntryInfo {
+        &self.info
+    }
+
+    /// The stage of its phase this entry runs in.
+    pub fn stage(&self) -> ButlerStage {
+        self.stage
+    }
+
+    /// The schedules this entry adds systems to.
+    pub fn schedules(&self) -> impl Iterator<Item = ButlerSchedule> {
+        self.schedules.iter().map(|schedule| schedule())
+    }
+}
+
+/// Returns the [`SubApp`] with the given label.
+///
+/// # Panics
+/// Panics if the sub app doesn't exist, as entries targeting a sub app don't create it.
+/// It's inserted by [`#[butler_sub_app]`](crate::butler_sub_app), whose entry runs in
+/// [`ButlerStage::InsertSubApps`] before the other entries of its phase, or can be inserted with [`App::insert_sub_app`] before the plugin is added.
+pub fn butler_sub_app_mut(app: &mut App, label: impl AppLabel) -> &mut SubApp {
+    let label = label.intern();
+    match app.get_sub_app_mut(label) {
+        Some(sub_app) => sub_app,
+        None => panic!(
+            "The sub app `{label:?}` doesn't exist, it must be inserted with `#[butler_sub_app]` \
+            or `App::insert_sub_app` before it can be targeted"
+        ),
+    }
+}
+
+/// Every [`ButlerEntry`] submitted with [`butler_entry!`](crate::butler_entry).
+#[cfg(not(any(target_arch = "wasm32", feature = "inventory")))]
+#[linkme::distributed_slice]
+pub static BUTLER_ENTRIES: [ButlerEntry] = [..];
+
+#[cfg(any(target_arch = "wasm32", feature = "inventory"))]
+::inventory::collect!(ButlerEntry);
+
+#[doc(hidden)]
+#[cfg(any(target_arch = "wasm32", feature = "inventory"))]
+pub use inventory;
+#[doc(hidden)]
+#[cfg(not(any(target_arch = "wasm32", feature = "inventory")))]
+pub use linkme;
+
+/// Submits a [`ButlerEntry`] to the butler registry.
+///
+/// The first argument is a unique identifier for the generated static, the second
+/// is a constant expression evaluating to the entry.
+#[cfg(not(any(target_arch = "wasm32", feature = "inventory")))]
+#[macro_export]
+macro_rules! butler_entry {
+    ($static_ident:ident, $entry:expr) => {
+        #[::bevy_butler::registry::linkme::distributed_slice(
+            ::bevy_butler::registry::BUTLER_ENTRIES
+        )]
+        #[linkme(crate = ::bevy_butler::registry::linkme)]
+        #[allow(non_upper_case_globals)]
+        static $static_ident: ::bevy_butler::registry::ButlerEntry = $entry;
+    };
+}
+
+/// Submits a [`ButlerEntry`] to the butler registry.
+///
+/// The first argument is a unique identifier for the generated static, the second
+/// is a constant expression evaluating to the entry.
+#[cfg(any(target_arch = "wasm32", feature = "inventory"))]
+#[macro_export]
+macro_rules! butler_entry {
+    ($static_ident:ident, $entry:expr) => {
+        ::bevy_butler::registry::inventory::submit!($entry);
+    };
+}
@@ -0,0 +1,95 @@
+//! Resources created by the debugging options of the state registration macros.
+//!
+//! ```rust
+//! # use bevy::prelude::*;
+//! # use bevy_butler::*;
+//! use bevy_butler::state::StateHistory;
+//! # #[butler_plugin]
+//! # struct GamePlugin;
+//!
+//! #[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
+//! #[insert_state(plugin = GamePlugin, history = 8)]
+//! enum GameState {
+//!     #[default]
+//!     Menu,
+//!     InGame,
+//! }
+//!
+//! fn print_history(history: Res<StateHistory<GameState>>) {
+//!     for record in history.iter() {
+//!         info!("Frame {}: {:?} => {:?}", record.frame, record.exited, record.entered);
+//!     }
+//! }
+//! ```
+use std::collections::VecDeque;
+
+use bevy_ecs::resource::Resource;
+use bevy_state::state::States;
+
+/// A single transition of the state `S`, as recorded in a [`StateHistory`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StateTransitionRecord<S: States> {
+    /// The state being exited.
+    pub exited: Option<S>,
+    /// The state being entered.
+    pub entered: Option<S>,
+    /// The [`FrameCount`](bevy_diagnostic::FrameCount) at the time of the transition,
+    /// or `0` if the app doesn't count frames.
+    pub frame: u32,
+}
+
+/// A ring buffer of the most recent transitions of the state `S`.
+///
+/// Inserted by the `history = N` option of [`insert_state`](crate::insert_state),
+/// [`add_sub_state`](crate::add_sub_state) and [`add_computed_state`](crate::add_computed_state).
+#[derive(Resource, Clone, Debug)]
+pub struct StateHistory<S: States> {
+    capacity: usize,
+    records: VecDeque<StateTransitionRecord<S>>,
+}
+
+impl<S: States> StateHistory<S> {
+    /// Creates an empty history holding at most `capacity` transitions.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            records: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /// Records a transition, dropping the oldest one if the history is full.
+    pub fn push(&mut self, record: StateTransitionRecord<S>) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.records.len() == self.capacity {
+            self.records.pop_front();
+        }
+        self.records.push_back(record);
+    }
+
+    /// The maximum number of transitions kept.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// The number of transitions currently recorded.
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    /// Returns `true` if no transitions have been recorded yet.
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// The most recent transition.
+    pub fn last(&self) -> Option<&StateTransitionRecord<S>> {
+        self.records.back()
+    }
+
+    /// Iterates over the recorded transitions, from oldest to newest.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &StateTransitionRecord<S>> {
+        self.records.iter()
+    }
+}
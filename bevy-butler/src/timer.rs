@@ -0,0 +1,139 @@
+//! The timers of systems added with [`add_system`](crate::add_system)'s `every` or `every_fixed_ticks`.
+//!
+//! Each periodic system gets a [`ButlerTimer`] in the [`ButlerTimers`] resource, looked up by the system function.
+//!
+//! ```rust
+//! # use std::time::Duration;
+//! # use bevy::prelude::*;
+//! # use bevy_butler::*;
+//! use bevy_butler::timer::ButlerTimers;
+//! # #[butler_plugin]
+//! # struct MyPlugin;
+//!
+//! #[add_system(plugin = MyPlugin, schedule = Update, every = Duration::from_secs(60))]
+//! fn autosave() {
+//!     info!("Saving...");
+//! }
+//!
+//! // Saving manually restarts the autosave timer
+//! fn save_now(mut timers: ResMut<ButlerTimers>) {
+//!     for timer in timers.iter_mut(autosave) {
+//!         timer.reset();
+//!     }
+//! }
+//! ```
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::time::Duration;
+
+use bevy_ecs::prelude::*;
+use bevy_time::Timer;
+
+/// The timer deciding when a periodic system runs.
+#[derive(Clone, Debug)]
+pub enum ButlerTimer {
+    /// Runs once every `period` of [`Time`](bevy_time::Time). The timer lasts the initial delay until it first finishes.
+    Every {
+        /// The timer until the next run.
+        timer: Timer,
+        /// The time between runs.
+        period: Duration,
+    },
+    /// Runs once every `period` runs of its schedule, which is meant to be a fixed timestep schedule.
+    FixedTicks {
+        /// The runs counted since the last time the system ran.
+        ticks: u32,
+        /// The number of runs between each time the system runs.
+        period: u32,
+    },
+}
+
+impl ButlerTimer {
+    /// Restarts the timer, so the system runs one full period (or initial delay) from now.
+    pub fn reset(&mut self) {
+        match self {
+            Self::Every { timer, .. } => timer.reset(),
+            Self::FixedTicks { ticks, .. } => *ticks = 0,
+        }
+    }
+
+    /// Advances the timer, and returns `true` if the system should run.
+    pub(crate) fn tick(&mut self, delta: Duration) -> bool {
+        match self {
+            Self::Every { timer, period } => {
+                if !timer.tick(delta).just_finished() {
+                    return false;
+                }
+                // The initial delay is over, so switch to the actual period
+                if timer.duration() != *period {
+                    timer.set_duration(*period);
+                }
+                true
+            }
+            Self::FixedTicks { ticks, period } => {
+                *ticks += 1;
+                if *ticks < *period {
+                    return false;
+                }
+                *ticks = 0;
+                true
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+struct TimerState {
+    /// The [`TypeId`] of the system function
+    function: TypeId,
+    timer: ButlerTimer,
+    /// Whether the system runs this time, set right before it by ticking the timer
+    finished: bool,
+}
+
+/// The [`ButlerTimer`] of every periodic system in this world.
+///
+/// Each `add_system` attribute has its own timer, so a function added in several schedules or by several
+/// plugins has one timer for each.
+#[derive(Resource, Default, Debug)]
+pub struct ButlerTimers(HashMap<TypeId, TimerState>);
+
+impl ButlerTimers {
+    /// Iterates over the timers of the given system function.
+    pub fn iter<F: 'static>(&self, _system: F) -> impl Iterator<Item = &ButlerTimer> {
+        self.0
+            .values()
+            .filter(|state| state.function == TypeId::of::<F>())
+            .map(|state| &state.timer)
+    }
+
+    /// Iterates mutably over the timers of the given system function.
+    pub fn iter_mut<F: 'static>(&mut self, _system: F) -> impl Iterator<Item = &mut ButlerTimer> {
+        self.0
+            .values_mut()
+            .filter(|state| state.function == TypeId::of::<F>())
+            .map(|state| &mut state.timer)
+    }
+
+    /// Adds the timer of the entry identified by `K`, running the function `F`.
+    pub(crate) fn insert<K: 'static, F: 'static>(&mut self, timer: ButlerTimer) {
+        let state = TimerState {
+            function: TypeId::of::<F>(),
+            timer,
+            finished: false,
+        };
+        self.0.insert(TypeId::of::<K>(), state);
+    }
+
+    pub(crate) fn tick<K: 'static>(&mut self, delta: Duration) {
+        if let Some(state) = self.0.get_mut(&TypeId::of::<K>()) {
+            state.finished = state.timer.tick(delta);
+        }
+    }
+
+    pub(crate) fn finished<K: 'static>(&self) -> bool {
+        self.0
+            .get(&TypeId::of::<K>())
+            .is_some_and(|state| state.finished)
+    }
+}
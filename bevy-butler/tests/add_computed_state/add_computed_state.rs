@@ -0,0 +1,58 @@
+use bevy_butler::*;
+use bevy::prelude::*;
+use bevy_state::app::StatesPlugin;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[butler_plugin]
+struct GamePlugin;
+
+#[insert_state(plugin = GamePlugin)]
+#[derive(States, Default, Debug, PartialEq, Eq, Hash, Clone)]
+enum GameState {
+    #[default]
+    Loading,
+    InGame { paused: bool }
+}
+
+#[add_computed_state(plugin = GamePlugin)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+enum Paused {
+    Yes,
+    No
+}
+
+impl ComputedStates for Paused {
+    type SourceStates = GameState;
+
+    fn compute(sources: GameState) -> Option<Self> {
+        match sources {
+            GameState::InGame { paused: true } => Some(Paused::Yes),
+            GameState::InGame { paused: false } => Some(Paused::No),
+            GameState::Loading => None,
+        }
+    }
+}
+
+#[add_system(plugin = GamePlugin, schedule = Startup)]
+fn enter_game(
+    mut next_state: ResMut<NextState<GameState>>
+) {
+    next_state.set(GameState::InGame { paused: true });
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test() {
+    let mut app = App::new();
+
+    app.add_plugins((StatesPlugin, GamePlugin));
+
+    let world = app.world_mut();
+    assert!(world.get_resource::<State<Paused>>().is_none());
+    world.run_schedule(Startup);
+    world.run_schedule(StateTransition);
+
+    assert_eq!(
+        *world.get_resource::<State<Paused>>().expect("Paused was not computed"),
+        Paused::Yes
+    );
+}
@@ -0,0 +1,41 @@
+use std::marker::PhantomData;
+
+use bevy_butler::*;
+use bevy::prelude::*;
+use bevy_state::app::StatesPlugin;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[butler_plugin]
+struct GamePlugin;
+
+#[insert_state(plugin = GamePlugin, init = GameState::InGame)]
+#[derive(States, Debug, PartialEq, Eq, Hash, Clone)]
+enum GameState {
+    InGame
+}
+
+#[add_computed_state(plugin = GamePlugin, generics = <u8>)]
+#[add_computed_state(plugin = GamePlugin, generics = <bool>)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+struct Marker<T>(PhantomData<T>);
+
+impl<T: Send + Sync + 'static + std::fmt::Debug + Eq + std::hash::Hash + Clone> ComputedStates for Marker<T> {
+    type SourceStates = GameState;
+
+    fn compute(_sources: GameState) -> Option<Self> {
+        Some(Marker(PhantomData))
+    }
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test() {
+    let mut app = App::new();
+
+    app.add_plugins((StatesPlugin, GamePlugin));
+
+    let world = app.world_mut();
+    world.run_schedule(StateTransition);
+
+    assert!(world.get_resource::<State<Marker<u8>>>().is_some());
+    assert!(world.get_resource::<State<Marker<bool>>>().is_some());
+}
@@ -0,0 +1,4 @@
+include!("../common.rs");
+
+mod add_computed_state;
+mod generic_computed_state;
@@ -0,0 +1,55 @@
+use bevy::prelude::*;
+use bevy_butler::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[butler_plugin]
+struct MyPlugin;
+
+#[derive(Component, Default)]
+struct Hits(u8);
+
+#[derive(Resource)]
+struct Bosses([Entity; 2]);
+
+#[derive(EntityEvent)]
+struct Damage {
+    entity: Entity,
+}
+
+#[add_system(plugin = MyPlugin, schedule = Startup)]
+fn spawn_bosses(mut commands: Commands) {
+    let bosses = [
+        commands.spawn(Hits::default()).id(),
+        commands.spawn(Hits::default()).id(),
+    ];
+    commands.insert_resource(Bosses(bosses));
+}
+
+fn bosses(world: &mut World) -> [Entity; 2] {
+    world.resource::<Bosses>().0
+}
+
+#[add_observer(plugin = MyPlugin, entities = bosses)]
+fn count_hits(damage: On<Damage>, mut hits: Query<&mut Hits>) {
+    hits.get_mut(damage.entity).unwrap().0 += 1;
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test() {
+    let mut app = App::new();
+    app.add_plugins(MyPlugin);
+    app.update();
+    let world = app.world_mut();
+
+    let [first, second] = world.resource::<Bosses>().0;
+    let minion = world.spawn(Hits::default()).id();
+
+    world.trigger(Damage { entity: first });
+    world.trigger(Damage { entity: second });
+    world.trigger(Damage { entity: second });
+    world.trigger(Damage { entity: minion });
+
+    assert_eq!(world.get::<Hits>(first).unwrap().0, 1);
+    assert_eq!(world.get::<Hits>(second).unwrap().0, 2);
+    assert_eq!(world.get::<Hits>(minion).unwrap().0, 0);
+}
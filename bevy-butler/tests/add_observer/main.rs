@@ -1,5 +1,9 @@
 include!("../common.rs");
 
+mod entities;
 mod generic_observer;
 mod observer;
 mod observer_use;
+mod scoped;
+mod transforms;
+mod watch;
@@ -0,0 +1,56 @@
+use bevy::prelude::*;
+use bevy_butler::*;
+use bevy_state::app::StatesPlugin;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[butler_plugin]
+struct MyPlugin;
+
+#[insert_state(plugin = MyPlugin)]
+#[derive(States, Default, Debug, PartialEq, Eq, Hash, Clone)]
+enum GameState {
+    #[default]
+    Menu,
+    Playing,
+}
+
+#[derive(Resource, Default)]
+#[insert_resource(plugin = MyPlugin)]
+struct Jumps(u8);
+
+#[derive(Event)]
+struct Jump;
+
+#[add_observer(plugin = MyPlugin, scoped_to = GameState::Playing)]
+fn count_jumps(_jump: On<Jump>, mut jumps: ResMut<Jumps>) {
+    jumps.0 += 1;
+}
+
+fn set_state(app: &mut App, state: GameState) {
+    app.world_mut()
+        .resource_mut::<NextState<GameState>>()
+        .set(state);
+    app.world_mut().run_schedule(StateTransition);
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test() {
+    let mut app = App::new();
+    app.add_plugins((StatesPlugin, MyPlugin));
+    app.world_mut().run_schedule(StateTransition);
+
+    app.world_mut().trigger(Jump);
+    assert_eq!(app.world().resource::<Jumps>().0, 0);
+
+    set_state(&mut app, GameState::Playing);
+    app.world_mut().trigger(Jump);
+    assert_eq!(app.world().resource::<Jumps>().0, 1);
+
+    set_state(&mut app, GameState::Menu);
+    app.world_mut().trigger(Jump);
+    assert_eq!(app.world().resource::<Jumps>().0, 1);
+
+    set_state(&mut app, GameState::Playing);
+    app.world_mut().trigger(Jump);
+    assert_eq!(app.world().resource::<Jumps>().0, 2);
+}
@@ -0,0 +1,69 @@
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use bevy::ecs::entity_disabling::Internal;
+use bevy::ecs::error::{BevyError, ErrorContext};
+use bevy::prelude::*;
+use bevy_butler::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[butler_plugin]
+struct MyPlugin;
+
+#[derive(Resource, Default)]
+#[insert_resource(plugin = MyPlugin)]
+struct Jumps(u8);
+
+#[derive(Resource)]
+struct CanJump;
+
+#[derive(Event)]
+struct Jump;
+
+#[derive(Component)]
+struct JumpObserver;
+
+#[derive(Component)]
+struct Tagged(u8);
+
+#[add_observer(
+    plugin = MyPlugin,
+    run_if = resource_exists::<CanJump>,
+    name = "count_jumps",
+    components = [JumpObserver, Tagged(3)],
+)]
+fn count_jumps(_jump: On<Jump>, mut jumps: ResMut<Jumps>) {
+    jumps.0 += 1;
+}
+
+static ERRORS: AtomicU8 = AtomicU8::new(0);
+
+fn count_errors(_error: BevyError, _context: ErrorContext) {
+    ERRORS.fetch_add(1, Ordering::Relaxed);
+}
+
+#[add_observer(plugin = MyPlugin, with_error_handler = count_errors)]
+fn failing_jump(_jump: On<Jump>) -> Result {
+    Err("can't jump".into())
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test() {
+    let mut app = App::new();
+    app.add_plugins(MyPlugin);
+    let world = app.world_mut();
+
+    world.trigger(Jump);
+    assert_eq!(world.resource::<Jumps>().0, 0);
+    assert_eq!(ERRORS.load(Ordering::Relaxed), 1);
+
+    world.insert_resource(CanJump);
+    world.trigger(Jump);
+    assert_eq!(world.resource::<Jumps>().0, 1);
+    assert_eq!(ERRORS.load(Ordering::Relaxed), 2);
+
+    let mut observers =
+        world.query_filtered::<(&Name, &Tagged), (With<JumpObserver>, Allow<Internal>)>();
+    let (name, tagged) = observers.single(world).unwrap();
+    assert_eq!(name.as_str(), "count_jumps");
+    assert_eq!(tagged.0, 3);
+}
@@ -0,0 +1,56 @@
+use bevy::ecs::entity_disabling::Internal;
+use bevy::prelude::*;
+use bevy_butler::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[butler_plugin]
+struct MyPlugin;
+
+#[derive(Component)]
+struct Enemy;
+
+#[derive(Component, Default)]
+struct Hits(u8);
+
+#[derive(EntityEvent)]
+struct Damage {
+    entity: Entity,
+}
+
+#[add_observer(plugin = MyPlugin, watch = Enemy)]
+fn count_hits(damage: On<Damage>, mut hits: Query<&mut Hits>) {
+    hits.get_mut(damage.entity).unwrap().0 += 1;
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test() {
+    let mut app = App::new();
+    app.add_plugins(MyPlugin);
+    let world = app.world_mut();
+
+    let enemy = world.spawn((Enemy, Hits::default())).id();
+    let friend = world.spawn(Hits::default()).id();
+
+    world.trigger(Damage { entity: enemy });
+    world.trigger(Damage { entity: friend });
+    assert_eq!(world.get::<Hits>(enemy).unwrap().0, 1);
+    assert_eq!(world.get::<Hits>(friend).unwrap().0, 0);
+
+    // Losing the component detaches the observer
+    world.entity_mut(enemy).remove::<Enemy>();
+    world.flush();
+    world.trigger(Damage { entity: enemy });
+    assert_eq!(world.get::<Hits>(enemy).unwrap().0, 1);
+
+    // And gaining it again attaches a new one
+    world.entity_mut(friend).insert(Enemy);
+    world.flush();
+    world.trigger(Damage { entity: friend });
+    assert_eq!(world.get::<Hits>(friend).unwrap().0, 1);
+
+    // Despawning the entity cleans up its observer
+    world.entity_mut(friend).despawn();
+    world.flush();
+    let mut observers = world.query_filtered::<(), (With<Observer>, Allow<Internal>)>();
+    assert_eq!(observers.iter(world).count(), 2);
+}
@@ -0,0 +1,61 @@
+use bevy::ecs::schedule::{ExecutorKind, ScheduleLabel};
+use bevy::prelude::*;
+use bevy_butler::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[butler_plugin]
+struct MyPlugin;
+
+#[derive(Resource, Default)]
+#[insert_resource(plugin = MyPlugin)]
+struct Order(Vec<&'static str>);
+
+#[add_schedule(plugin = MyPlugin, after = Update, executor = SingleThreaded)]
+#[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+struct NetworkTick;
+
+#[add_schedule(plugin = MyPlugin, startup, before = Startup)]
+#[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+struct EarlyStartup;
+
+#[add_schedule(plugin = MyPlugin)]
+#[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+struct Manual;
+
+#[add_system(plugin = MyPlugin, schedule = EarlyStartup)]
+fn early_startup(mut order: ResMut<Order>) {
+    order.0.push("early_startup");
+}
+
+#[add_system(plugin = MyPlugin, schedule = Startup)]
+fn startup(mut order: ResMut<Order>) {
+    order.0.push("startup");
+}
+
+#[add_system(plugin = MyPlugin, schedule = NetworkTick)]
+fn network_tick(mut order: ResMut<Order>) {
+    order.0.push("network_tick");
+}
+
+#[add_system(plugin = MyPlugin, schedule = Update)]
+fn update(mut order: ResMut<Order>) {
+    order.0.push("update");
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test() {
+    let mut app = App::new();
+    app.add_plugins(MyPlugin);
+
+    assert_eq!(
+        app.get_schedule(NetworkTick).unwrap().get_executor_kind(),
+        ExecutorKind::SingleThreaded
+    );
+    assert!(app.get_schedule(Manual).is_some());
+
+    app.update();
+    assert_eq!(
+        app.world().resource::<Order>().0,
+        ["early_startup", "startup", "update", "network_tick"]
+    );
+}
@@ -0,0 +1,25 @@
+use bevy::app::FixedMainScheduleOrder;
+use bevy::ecs::schedule::ScheduleLabel;
+use bevy::prelude::*;
+use bevy_butler::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[butler_plugin]
+struct MyPlugin;
+
+#[add_schedule(plugin = MyPlugin, fixed, after = FixedUpdate)]
+#[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+struct AiPlanning;
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test() {
+    let mut app = App::new();
+    app.add_plugins(MyPlugin);
+
+    let labels = &app.world().resource::<FixedMainScheduleOrder>().labels;
+    let fixed_update = labels
+        .iter()
+        .position(|label| *label == FixedUpdate.intern())
+        .unwrap();
+    assert_eq!(labels[fixed_update + 1], AiPlanning.intern());
+}
@@ -0,0 +1,5 @@
+include!("../common.rs");
+
+mod add_schedule;
+mod fixed;
+mod missing_order;
@@ -0,0 +1,20 @@
+use bevy::app::MainScheduleOrder;
+use bevy::ecs::schedule::ScheduleLabel;
+use bevy::prelude::*;
+use bevy_butler::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[butler_plugin]
+struct MyPlugin;
+
+#[add_schedule(plugin = MyPlugin, after = Update)]
+#[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+struct LateUpdate;
+
+#[wasm_bindgen_test(unsupported = test)]
+#[should_panic(expected = "`LateUpdate` at ")]
+fn test() {
+    let mut app = App::new();
+    app.world_mut().remove_resource::<MainScheduleOrder>();
+    app.add_plugins(MyPlugin);
+}
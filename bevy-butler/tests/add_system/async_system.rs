@@ -0,0 +1,68 @@
+use std::time::Duration;
+
+use bevy::ecs::world::CommandQueue;
+use bevy::platform::time::Instant;
+use bevy::prelude::*;
+use bevy_butler::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[butler_plugin]
+struct MyPlugin;
+
+#[derive(Resource)]
+struct SaveFile(String);
+
+#[derive(Resource)]
+struct Path(Vec<u32>);
+
+#[add_system(plugin = MyPlugin, schedule = Startup, async)]
+async fn load_save() -> CommandQueue {
+    let contents = String::from("level 3");
+    let mut commands = CommandQueue::default();
+    commands.push(move |world: &mut World| {
+        world.insert_resource(SaveFile(contents));
+    });
+    commands
+}
+
+fn path_request() -> u32 {
+    4
+}
+
+#[add_system(plugin = MyPlugin, schedule = Startup, pipe_in = [path_request], async)]
+async fn find_path(length: u32) -> Result<CommandQueue> {
+    let path = (0..length).collect();
+    let mut commands = CommandQueue::default();
+    commands.push(move |world: &mut World| {
+        world.insert_resource(Path(path));
+    });
+    Ok(commands)
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test() {
+    let mut app = App::new();
+    app.add_plugins((TaskPoolPlugin::default(), MyPlugin));
+
+    // Keep updating until both futures are applied, giving the task pool time to run them
+    let deadline = Instant::now() + Duration::from_secs(10);
+    loop {
+        app.update();
+        if app.world().contains_resource::<SaveFile>() && app.world().contains_resource::<Path>() {
+            break;
+        }
+        assert!(
+            Instant::now() < deadline,
+            "the async systems didn't complete in time"
+        );
+        std::thread::yield_now();
+    }
+
+    assert_eq!(app.world().resource::<SaveFile>().0, "level 3");
+    assert_eq!(app.world().resource::<Path>().0, [0, 1, 2, 3]);
+    // The task entities are despawned once applied
+    assert_eq!(
+        app.world_mut().query::<Entity>().iter(app.world()).count(),
+        0
+    );
+}
@@ -0,0 +1,99 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::time::{TimePlugin, TimeUpdateStrategy};
+use bevy_butler::timer::{ButlerTimer, ButlerTimers};
+use bevy_butler::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[butler_plugin]
+struct MyPlugin;
+
+#[butler_plugin]
+struct OtherPlugin;
+
+#[derive(Resource, Default)]
+#[insert_resource(plugin = MyPlugin)]
+struct Runs {
+    autosave: u32,
+    telemetry: u32,
+    replan: u32,
+    heartbeat: u32,
+}
+
+#[add_system(plugin = MyPlugin, schedule = Update, every = Duration::from_secs(2))]
+fn autosave(mut runs: ResMut<Runs>) {
+    runs.autosave += 1;
+}
+
+#[add_system(
+    plugin = MyPlugin,
+    schedule = Update,
+    every = Duration::from_secs(2),
+    initial_delay = Duration::from_secs(5),
+)]
+fn telemetry(mut runs: ResMut<Runs>) {
+    runs.telemetry += 1;
+}
+
+#[add_system(plugin = MyPlugin, schedule = FixedUpdate, every_fixed_ticks = 3)]
+fn replan(mut runs: ResMut<Runs>) {
+    runs.replan += 1;
+}
+
+// Each attribute runs the function on its own timer
+#[add_system(plugin = MyPlugin, schedule = Update, every = Duration::from_secs(2))]
+#[add_system(plugin = MyPlugin, schedule = PostUpdate, every = Duration::from_secs(1))]
+#[add_system(plugin = OtherPlugin, schedule = Update, every = Duration::from_secs(5))]
+fn heartbeat(mut runs: ResMut<Runs>) {
+    runs.heartbeat += 1;
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test() {
+    let mut app = App::new();
+    app.add_plugins((TimePlugin, MyPlugin, OtherPlugin))
+        .insert_resource(Time::<Fixed>::from_seconds(1.0))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            250,
+        )));
+
+    // Advance 10 seconds, as the first update has no delta
+    for _ in 0..=40 {
+        app.update();
+    }
+
+    let runs = app.world().resource::<Runs>();
+    assert_eq!(runs.autosave, 5);
+    // At 5s, 7s and 9s
+    assert_eq!(runs.telemetry, 3);
+    assert_eq!(runs.replan, 3);
+    // 5 runs every 2s, 10 every second and 2 every 5s
+    assert_eq!(runs.heartbeat, 17);
+
+    let timers = app.world().resource::<ButlerTimers>();
+    assert!(matches!(
+        timers.iter(autosave).collect::<Vec<_>>()[..],
+        [ButlerTimer::Every { .. }]
+    ));
+    assert!(matches!(
+        timers.iter(replan).collect::<Vec<_>>()[..],
+        [ButlerTimer::FixedTicks { period: 3, .. }]
+    ));
+    assert_eq!(timers.iter(heartbeat).count(), 3);
+
+    // Restarting the timer a second into the period delays the next run to 2 seconds from then
+    for _ in 0..4 {
+        app.update();
+    }
+    app.world_mut()
+        .resource_mut::<ButlerTimers>()
+        .iter_mut(autosave)
+        .for_each(ButlerTimer::reset);
+    for _ in 0..7 {
+        app.update();
+    }
+    assert_eq!(app.world().resource::<Runs>().autosave, 5);
+    app.update();
+    assert_eq!(app.world().resource::<Runs>().autosave, 6);
+}
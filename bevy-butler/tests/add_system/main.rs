@@ -1,8 +1,11 @@
 include!("../common.rs");
 
+mod async_system;
+mod every;
 mod generic_pipe;
 mod generic_system;
 mod pipe;
+mod pipe_out;
 mod system;
 mod system_expr_schedule;
 mod use_declaration;
@@ -0,0 +1,60 @@
+use bevy::prelude::*;
+use bevy_butler::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[butler_plugin]
+struct MyPlugin;
+
+#[derive(Resource, Default)]
+#[insert_resource(plugin = MyPlugin)]
+struct Report {
+    spawned: usize,
+    errors: usize,
+    doubled: usize,
+}
+
+fn handle_result(result: In<Result<usize>>) -> Option<usize> {
+    result.0.ok()
+}
+
+fn record_spawned(spawned: In<Option<usize>>, mut report: ResMut<Report>) {
+    match spawned.0 {
+        Some(count) => report.spawned += count,
+        None => report.errors += 1,
+    }
+}
+
+#[add_system(plugin = MyPlugin, schedule = Startup, pipe_out = [handle_result, record_spawned])]
+fn spawn_wave() -> Result<usize> {
+    Ok(5)
+}
+
+#[add_system(plugin = MyPlugin, schedule = Startup, pipe_out = [handle_result, record_spawned])]
+fn spawn_broken_wave() -> Result<usize> {
+    Err("no spawn points".into())
+}
+
+fn double(count: usize) -> usize {
+    count * 2
+}
+
+fn record_doubled(doubled: In<usize>, mut report: ResMut<Report>) {
+    report.doubled = doubled.0;
+}
+
+#[add_system(plugin = MyPlugin, schedule = Startup, map = double, pipe_out = [record_doubled])]
+fn count_enemies() -> usize {
+    21
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test() {
+    let mut app = App::new();
+    app.add_plugins(MyPlugin);
+    app.update();
+
+    let report = app.world().resource::<Report>();
+    assert_eq!(report.spawned, 5);
+    assert_eq!(report.errors, 1);
+    assert_eq!(report.doubled, 42);
+}
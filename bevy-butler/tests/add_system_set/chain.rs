@@ -0,0 +1,50 @@
+use bevy::prelude::*;
+use bevy_butler::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[butler_plugin]
+struct MyPlugin;
+
+#[derive(Resource, Default)]
+#[insert_resource(plugin = MyPlugin)]
+struct Order(Vec<&'static str>);
+
+#[derive(Resource)]
+#[insert_resource(plugin = MyPlugin, init = Enabled(true))]
+struct Enabled(bool);
+
+#[add_system_set(plugin = MyPlugin, schedule = Update, run_if = |enabled: Res<Enabled>| enabled.0, chain)]
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+enum GameSet {
+    Input,
+    Physics,
+    Render,
+}
+
+#[add_system(plugin = MyPlugin, schedule = Update, in_set = GameSet::Render)]
+fn render(mut order: ResMut<Order>) {
+    order.0.push("render");
+}
+
+#[add_system(plugin = MyPlugin, schedule = Update, in_set = GameSet::Physics)]
+fn physics(mut order: ResMut<Order>) {
+    order.0.push("physics");
+}
+
+#[add_system(plugin = MyPlugin, schedule = Update, in_set = GameSet::Input)]
+fn input(mut order: ResMut<Order>) {
+    order.0.push("input");
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test() {
+    let mut app = App::new();
+    app.add_plugins(MyPlugin);
+
+    app.update();
+    assert_eq!(app.world().resource::<Order>().0, ["input", "physics", "render"]);
+
+    app.world_mut().resource_mut::<Enabled>().0 = false;
+    app.update();
+    assert_eq!(app.world().resource::<Order>().0.len(), 3);
+}
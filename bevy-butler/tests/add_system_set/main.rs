@@ -0,0 +1,5 @@
+include!("../common.rs");
+
+mod chain;
+mod unit_struct;
+mod variant_config;
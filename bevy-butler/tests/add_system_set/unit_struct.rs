@@ -0,0 +1,28 @@
+use bevy::prelude::*;
+use bevy_butler::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[butler_plugin]
+struct MyPlugin;
+
+#[derive(Resource, Default)]
+#[insert_resource(plugin = MyPlugin)]
+struct Ran(bool);
+
+#[add_system_set(plugin = MyPlugin, schedule = Update, run_if = || false)]
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+struct NeverSet;
+
+#[add_system(plugin = MyPlugin, schedule = Update, in_set = NeverSet)]
+fn never(mut ran: ResMut<Ran>) {
+    ran.0 = true;
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test() {
+    let mut app = App::new();
+    app.add_plugins(MyPlugin);
+
+    app.update();
+    assert!(!app.world().resource::<Ran>().0);
+}
@@ -0,0 +1,52 @@
+use bevy::prelude::*;
+use bevy_butler::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[butler_plugin]
+struct MyPlugin;
+
+#[derive(Resource, Default)]
+#[insert_resource(plugin = MyPlugin)]
+struct Order(Vec<&'static str>);
+
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+struct Late;
+
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+#[add_system_set(plugin = MyPlugin, schedule = Update, before = Late)]
+enum GameSet {
+    #[butler(after = GameSet::Input)]
+    Physics,
+    Input,
+    #[butler(after = GameSet::Physics, run_if = || false)]
+    Disabled,
+}
+
+#[add_system(plugin = MyPlugin, schedule = Update, in_set = Late)]
+fn late(mut order: ResMut<Order>) {
+    order.0.push("late");
+}
+
+#[add_system(plugin = MyPlugin, schedule = Update, in_set = GameSet::Physics)]
+fn physics(mut order: ResMut<Order>) {
+    order.0.push("physics");
+}
+
+#[add_system(plugin = MyPlugin, schedule = Update, in_set = GameSet::Input)]
+fn input(mut order: ResMut<Order>) {
+    order.0.push("input");
+}
+
+#[add_system(plugin = MyPlugin, schedule = Update, in_set = GameSet::Disabled)]
+fn disabled(mut order: ResMut<Order>) {
+    order.0.push("disabled");
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test() {
+    let mut app = App::new();
+    app.add_plugins(MyPlugin);
+
+    app.update();
+    assert_eq!(app.world().resource::<Order>().0, ["input", "physics", "late"]);
+}
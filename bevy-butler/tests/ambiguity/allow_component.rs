@@ -0,0 +1,34 @@
+use bevy::prelude::*;
+use bevy_butler::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[butler_plugin(ambiguity_detection = error)]
+struct MyPlugin;
+
+#[derive(Component)]
+#[allow_ambiguous_component(plugin = MyPlugin)]
+struct Health(u32);
+
+#[add_system(plugin = MyPlugin, schedule = Update)]
+fn heal(mut query: Query<&mut Health>) {
+    for mut health in &mut query {
+        health.0 += 1;
+    }
+}
+
+#[add_system(plugin = MyPlugin, schedule = Update)]
+fn damage(mut query: Query<&mut Health>) {
+    for mut health in &mut query {
+        health.0 -= 1;
+    }
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test() {
+    let mut app = App::new();
+    app.add_plugins(MyPlugin);
+    app.world_mut().spawn(Health(10));
+    app.finish();
+    app.cleanup();
+    app.update();
+}
@@ -0,0 +1,30 @@
+use bevy::prelude::*;
+use bevy_butler::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[butler_plugin(ambiguity_detection = error)]
+struct MyPlugin;
+
+#[derive(Resource, Default)]
+#[insert_resource(plugin = MyPlugin)]
+#[allow_ambiguous_resource(plugin = MyPlugin)]
+struct Score(u32);
+
+#[add_system(plugin = MyPlugin, schedule = Update)]
+fn add_score(mut score: ResMut<Score>) {
+    score.0 += 1;
+}
+
+#[add_system(plugin = MyPlugin, schedule = Update)]
+fn double_score(mut score: ResMut<Score>) {
+    score.0 *= 2;
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test() {
+    let mut app = App::new();
+    app.add_plugins(MyPlugin);
+    app.finish();
+    app.cleanup();
+    app.update();
+}
@@ -0,0 +1,29 @@
+use bevy::ecs::schedule::{LogLevel, ScheduleLabel};
+use bevy::prelude::*;
+use bevy_butler::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[butler_plugin]
+struct MyPlugin;
+
+#[configure_schedule(plugin = MyPlugin, ambiguity_detection = error, report_sets = false)]
+#[add_schedule(plugin = MyPlugin)]
+#[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+struct NetworkTick;
+
+#[configure_schedule(plugin = MyPlugin, hierarchy_detection = ignore)]
+use bevy::prelude::PostUpdate;
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test() {
+    let mut app = App::new();
+    app.add_plugins(MyPlugin);
+
+    let settings = app.get_schedule(NetworkTick).unwrap().get_build_settings();
+    assert_eq!(settings.ambiguity_detection, LogLevel::Error);
+    assert!(!settings.report_sets);
+
+    let settings = app.get_schedule(PostUpdate).unwrap().get_build_settings();
+    assert_eq!(settings.hierarchy_detection, LogLevel::Ignore);
+    assert_eq!(settings.ambiguity_detection, LogLevel::Ignore);
+}
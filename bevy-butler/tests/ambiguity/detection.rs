@@ -0,0 +1,50 @@
+use bevy::ecs::schedule::LogLevel;
+use bevy::prelude::*;
+use bevy_butler::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[butler_plugin(ambiguity_detection = error, auto_insert_apply_deferred = false)]
+struct MyPlugin;
+
+#[derive(Resource, Default)]
+#[insert_resource(plugin = MyPlugin)]
+struct Score(u32);
+
+#[add_system(plugin = MyPlugin, schedule = Update)]
+fn add_score(mut score: ResMut<Score>) {
+    score.0 += 1;
+}
+
+#[add_system(plugin = MyPlugin, schedule = Update)]
+fn double_score(mut score: ResMut<Score>) {
+    score.0 *= 2;
+}
+
+fn build_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MyPlugin);
+    app.finish();
+    app.cleanup();
+    app
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn settings() {
+    let app = build_app();
+    let settings = app.get_schedule(Update).unwrap().get_build_settings();
+    assert_eq!(settings.ambiguity_detection, LogLevel::Error);
+    assert!(!settings.auto_insert_apply_deferred);
+    // Untouched settings keep their defaults
+    assert_eq!(settings.hierarchy_detection, LogLevel::Warn);
+
+    // Schedules the plugin doesn't add systems to are left alone
+    let settings = app.get_schedule(Main).unwrap().get_build_settings();
+    assert_eq!(settings.ambiguity_detection, LogLevel::Ignore);
+    assert!(settings.auto_insert_apply_deferred);
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+#[should_panic]
+fn ambiguous() {
+    build_app().update();
+}
@@ -0,0 +1,50 @@
+use bevy::prelude::*;
+use bevy_butler::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[butler_plugin(ambiguity_detection = error)]
+struct MyPlugin;
+
+#[derive(Resource, Default)]
+#[insert_resource(plugin = MyPlugin)]
+struct Score(u32);
+
+#[derive(Resource)]
+#[insert_resource(plugin = MyPlugin, init = Bonus(10))]
+struct Bonus(u32);
+
+#[add_system_set(plugin = MyPlugin, schedule = Update)]
+#[ignore_ambiguity(plugin = MyPlugin, schedule = Update, with = add_score)]
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+struct BonusSet;
+
+#[add_system(plugin = MyPlugin, schedule = Update)]
+fn add_score(mut score: ResMut<Score>) {
+    score.0 += 1;
+}
+
+#[add_system(plugin = MyPlugin, schedule = Update)]
+#[ignore_ambiguity(plugin = MyPlugin, schedule = Update, with = [add_score, bonus_score])]
+fn double_score(mut score: ResMut<Score>) {
+    score.0 *= 2;
+}
+
+#[add_system(plugin = MyPlugin, schedule = Update, in_set = BonusSet)]
+fn bonus_score(mut score: ResMut<Score>, bonus: Res<Bonus>) {
+    score.0 += bonus.0;
+}
+
+#[add_system(plugin = MyPlugin, schedule = Update)]
+#[ignore_ambiguity(plugin = MyPlugin, schedule = Update, with = BonusSet)]
+fn decay_bonus(mut bonus: ResMut<Bonus>) {
+    bonus.0 = bonus.0.saturating_sub(1);
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test() {
+    let mut app = App::new();
+    app.add_plugins(MyPlugin);
+    app.finish();
+    app.cleanup();
+    app.update();
+}
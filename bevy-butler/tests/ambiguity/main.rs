@@ -0,0 +1,7 @@
+include!("../common.rs");
+
+mod allow_component;
+mod allow_resource;
+mod configure_schedule;
+mod detection;
+mod ignore_ambiguity;
@@ -0,0 +1,51 @@
+use bevy::prelude::*;
+use bevy_butler::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[butler_plugin]
+struct AiPlugin;
+
+#[derive(Resource, Default)]
+#[insert_resource(plugin = AiPlugin)]
+struct Log(Vec<&'static str>);
+
+#[butler_impl]
+impl AiPlugin {
+    #[add_system(plugin = Self, schedule = Startup)]
+    fn setup(mut log: ResMut<Log>) {
+        log.0.push("setup");
+    }
+
+    #[add_system(plugin = Self, schedule = Startup, after = Self::setup)]
+    fn after_setup(mut log: ResMut<Log>) {
+        log.0.push("after_setup");
+    }
+}
+
+struct EnemyAi;
+
+#[butler_impl]
+impl EnemyAi {
+    #[add_system(plugin = AiPlugin, schedule = Startup, after = AiPlugin::after_setup)]
+    #[add_system(plugin = AiPlugin, schedule = Update)]
+    fn think(mut log: ResMut<Log>) {
+        log.0.push("think");
+    }
+
+    fn not_a_system() -> u32 {
+        3
+    }
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test() {
+    let mut app = App::new();
+    app.add_plugins(AiPlugin);
+    app.update();
+
+    assert_eq!(
+        app.world().resource::<Log>().0,
+        ["setup", "after_setup", "think", "think"]
+    );
+    assert_eq!(EnemyAi::not_a_system(), 3);
+}
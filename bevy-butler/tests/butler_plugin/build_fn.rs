@@ -0,0 +1,43 @@
+use bevy_app::prelude::*;
+use bevy_butler::*;
+use bevy_ecs::prelude::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+use crate::common::log_plugin;
+
+#[butler_plugin]
+struct MyPlugin;
+
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+enum Steps {
+    First,
+    Second,
+}
+
+#[derive(Resource, Default)]
+#[insert_resource(plugin = MyPlugin)]
+struct Order(Vec<u8>);
+
+#[butler_build(plugin = MyPlugin)]
+fn configure_steps(app: &mut App) {
+    app.configure_sets(Startup, (Steps::First, Steps::Second).chain());
+}
+
+#[add_system(plugin = MyPlugin, schedule = Startup, in_set = Steps::Second)]
+fn second(mut order: ResMut<Order>) {
+    order.0.push(2);
+}
+
+#[add_system(plugin = MyPlugin, schedule = Startup, in_set = Steps::First)]
+fn first(mut order: ResMut<Order>) {
+    order.0.push(1);
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test() {
+    App::new()
+        .add_plugins(log_plugin())
+        .add_plugins(MyPlugin)
+        .add_systems(PostStartup, |order: Res<Order>| assert_eq!(order.0, [1, 2]))
+        .run();
+}
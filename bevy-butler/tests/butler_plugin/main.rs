@@ -1,6 +1,9 @@
 include!("../common.rs");
 
+mod build_fn;
 mod butler_plugin;
 mod butler_plugin_enum;
 mod butler_plugin_impl;
 mod multiple_plugins;
+mod phases;
+mod register_position;
@@ -0,0 +1,61 @@
+use bevy_app::prelude::*;
+use bevy_butler::*;
+use bevy_ecs::prelude::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+use crate::common::log_plugin;
+
+#[butler_plugin]
+struct MyPlugin;
+
+struct OtherPlugin;
+
+#[derive(Resource)]
+struct Config(u32);
+
+#[derive(Resource)]
+struct Derived(u32);
+
+#[derive(Resource, Default)]
+#[insert_resource(plugin = MyPlugin, phase = cleanup)]
+struct CleanedUp;
+
+#[derive(Resource, Default)]
+struct OtherFinished;
+
+#[butler_plugin]
+impl Plugin for OtherPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(Config(7));
+    }
+
+    fn finish(&self, app: &mut App) {
+        app.init_resource::<OtherFinished>();
+    }
+}
+
+#[butler_finish(plugin = MyPlugin)]
+fn derive_config(app: &mut App) {
+    let config = app.world().resource::<Config>().0;
+    app.insert_resource(Derived(config * 2));
+}
+
+#[butler_cleanup(plugin = OtherPlugin)]
+fn check_derived(app: &mut App) {
+    assert_eq!(app.world().resource::<Derived>().0, 14);
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test() {
+    let mut app = App::new();
+    // MyPlugin is added first, so its `finish` relies on `OtherPlugin::build` having run
+    app.add_plugins((log_plugin(), MyPlugin, OtherPlugin));
+
+    assert!(!app.world().contains_resource::<Derived>());
+    app.finish();
+    assert_eq!(app.world().resource::<Derived>().0, 14);
+    assert!(app.world().contains_resource::<OtherFinished>());
+    assert!(!app.world().contains_resource::<CleanedUp>());
+    app.cleanup();
+    assert!(app.world().contains_resource::<CleanedUp>());
+}
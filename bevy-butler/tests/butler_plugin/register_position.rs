@@ -0,0 +1,52 @@
+use bevy_app::prelude::*;
+use bevy_butler::*;
+use bevy_ecs::prelude::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+use crate::common::log_plugin;
+
+#[derive(Resource)]
+struct Difficulty(u8);
+
+#[derive(Resource)]
+#[insert_resource(plugin = EndPlugin)]
+struct EnemyCount(u8);
+
+impl FromWorld for EnemyCount {
+    fn from_world(world: &mut World) -> Self {
+        EnemyCount(world.resource::<Difficulty>().0 * 2)
+    }
+}
+
+#[derive(Resource)]
+#[insert_resource(plugin = MarkerPlugin, init = Spawned(1))]
+struct Spawned(u8);
+
+struct EndPlugin;
+
+#[butler_plugin(register = end)]
+impl Plugin for EndPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(Difficulty(3));
+    }
+}
+
+struct MarkerPlugin;
+
+#[butler_plugin]
+impl Plugin for MarkerPlugin {
+    fn build(&self, app: &mut App) {
+        assert!(!app.world().contains_resource::<Spawned>());
+        butler_register!(app);
+        assert!(app.world().contains_resource::<Spawned>());
+    }
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test() {
+    let mut app = App::new();
+    app.add_plugins((log_plugin(), EndPlugin, MarkerPlugin));
+
+    assert_eq!(app.world().resource::<EnemyCount>().0, 6);
+    assert_eq!(app.world().resource::<Spawned>().0, 1);
+}
@@ -0,0 +1,48 @@
+use bevy::ecs::schedule::ScheduleLabel;
+use bevy::prelude::*;
+use bevy_butler::check::{check_schedules, ButlerScheduleCheckPlugin, ButlerScheduleTargets};
+use bevy_butler::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[butler_plugin]
+struct MyPlugin;
+
+#[derive(States, Default, Debug, PartialEq, Eq, Hash, Clone)]
+enum GameState {
+    #[default]
+    Menu,
+}
+
+#[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+struct NeverRun;
+
+#[add_system_set(plugin = MyPlugin, schedule = NeverRun)]
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+struct LostSet;
+
+#[on_enter(plugin = MyPlugin, state = GameState::Menu)]
+fn enter_menu() {}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test() {
+    // Without `StatesPlugin`, nothing runs the state transition schedules
+    let mut app = App::new();
+    app.add_plugins((ButlerScheduleCheckPlugin::default(), MyPlugin));
+
+    let targets = app.world().resource::<ButlerScheduleTargets>();
+    let enter = targets
+        .iter()
+        .find(|(_, info)| info.name == "enter_menu")
+        .unwrap();
+    assert_eq!(enter.0.label, OnEnter(GameState::Menu).intern());
+    assert!(enter.0.state_transition);
+    let set = targets
+        .iter()
+        .find(|(_, info)| info.name == "LostSet")
+        .unwrap();
+    assert_eq!(set.0.label, NeverRun.intern());
+    assert!(!set.0.state_transition);
+
+    let err = check_schedules(app.world()).unwrap_err();
+    assert_eq!(err.0.len(), 2);
+}
@@ -0,0 +1,5 @@
+include!("../common.rs");
+
+mod entry_kinds;
+mod reachable;
+mod unreachable;
@@ -0,0 +1,46 @@
+use bevy::ecs::schedule::ScheduleLabel;
+use bevy::prelude::*;
+use bevy_butler::check::ButlerScheduleCheckPlugin;
+use bevy_butler::*;
+use bevy_state::app::StatesPlugin;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[butler_plugin]
+struct MyPlugin;
+
+#[insert_state(plugin = MyPlugin)]
+#[derive(States, Default, Debug, PartialEq, Eq, Hash, Clone)]
+enum GameState {
+    #[default]
+    Menu,
+}
+
+#[add_schedule(plugin = MyPlugin, after = Update)]
+#[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+struct NetworkTick;
+
+#[add_schedule(plugin = MyPlugin, fixed, after = FixedUpdate)]
+#[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+struct AiPlanning;
+
+#[add_schedule(plugin = MyPlugin, manual)]
+#[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+struct Replay;
+
+#[add_system(plugin = MyPlugin, schedule = Startup)]
+#[add_system(plugin = MyPlugin, schedule = Update)]
+#[add_system(plugin = MyPlugin, schedule = FixedUpdate)]
+#[add_system(plugin = MyPlugin, schedule = NetworkTick)]
+#[add_system(plugin = MyPlugin, schedule = AiPlanning)]
+#[add_system(plugin = MyPlugin, schedule = Replay)]
+#[add_system(plugin = MyPlugin, schedule = OnEnter(GameState::Menu))]
+fn system() {}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test() {
+    let mut app = App::new();
+    app.add_plugins((StatesPlugin, ButlerScheduleCheckPlugin::default(), MyPlugin));
+    app.finish();
+    app.cleanup();
+    app.update();
+}
@@ -0,0 +1,60 @@
+use bevy::ecs::schedule::ScheduleLabel;
+use bevy::prelude::*;
+use bevy_butler::check::{check_schedules, ButlerScheduleCheckPlugin, ButlerScheduleTargets};
+use bevy_butler::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[butler_plugin]
+struct MyPlugin;
+
+#[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+struct NeverRun;
+
+#[add_system(plugin = MyPlugin, schedule = NeverRun)]
+fn lost_system() {}
+
+#[add_system(plugin = MyPlugin, schedule = Update)]
+fn found_system() {}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn reports_entry() {
+    let mut app = App::new();
+    app.add_plugins((ButlerScheduleCheckPlugin::default(), MyPlugin));
+
+    let err = check_schedules(app.world()).unwrap_err();
+    assert_eq!(err.0.len(), 1);
+    assert_eq!(err.0[0].schedule, NeverRun.intern());
+    assert_eq!(err.0[0].info.name, "lost_system");
+    assert!(err.0[0].info.file.ends_with("unreachable.rs"));
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+#[should_panic(expected = "lost_system")]
+fn plugin_panics() {
+    let mut app = App::new();
+    app.add_plugins((ButlerScheduleCheckPlugin::default(), MyPlugin));
+    app.finish();
+    app.cleanup();
+    app.update();
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn manually_run() {
+    let mut app = App::new();
+    app.add_plugins((
+        ButlerScheduleCheckPlugin::default().manually_run(NeverRun),
+        MyPlugin,
+    ));
+    app.finish();
+    app.cleanup();
+    app.update();
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn opt_in() {
+    let mut app = App::new();
+    app.add_plugins(MyPlugin);
+
+    assert!(!app.world().contains_resource::<ButlerScheduleTargets>());
+    assert!(check_schedules(app.world()).is_ok());
+}
@@ -0,0 +1,44 @@
+use bevy::ecs::lifecycle::HookContext;
+use bevy::ecs::world::DeferredWorld;
+use bevy::prelude::*;
+use bevy_butler::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[butler_plugin]
+struct PluginA;
+
+#[butler_plugin]
+struct PluginB;
+
+#[derive(Component)]
+struct Foreign;
+
+#[on_add(plugin = PluginA, component = Foreign)]
+fn first_hook(_world: DeferredWorld, _context: HookContext) {}
+
+#[on_add(plugin = PluginB, component = Foreign)]
+fn second_hook(_world: DeferredWorld, _context: HookContext) {}
+
+#[derive(Component)]
+#[component(on_add = own_hook)]
+struct Owned;
+
+fn own_hook(_world: DeferredWorld, _context: HookContext) {}
+
+#[wasm_bindgen_test(unsupported = test)]
+#[should_panic(expected = "already set by `first_hook`")]
+fn between_plugins() {
+    App::new().add_plugins((PluginA, PluginB));
+}
+
+#[butler_plugin]
+struct PluginC;
+
+#[on_add(plugin = PluginC, component = Owned)]
+fn owned_hook(_world: DeferredWorld, _context: HookContext) {}
+
+#[wasm_bindgen_test(unsupported = test)]
+#[should_panic(expected = "already set by the component's own definition")]
+fn with_derive() {
+    App::new().add_plugins(PluginC);
+}
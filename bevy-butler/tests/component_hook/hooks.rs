@@ -0,0 +1,66 @@
+use bevy::ecs::lifecycle::HookContext;
+use bevy::ecs::world::DeferredWorld;
+use bevy::prelude::*;
+use bevy_butler::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[butler_plugin]
+struct MyPlugin;
+
+#[derive(Resource, Default)]
+#[insert_resource(plugin = MyPlugin)]
+struct Log(Vec<&'static str>);
+
+// Stand-in for a component from another crate
+#[derive(Component)]
+struct Foreign;
+
+#[on_add(plugin = MyPlugin, component = Foreign)]
+fn added(mut world: DeferredWorld, _context: HookContext) {
+    world.resource_mut::<Log>().0.push("add");
+}
+
+#[on_insert(plugin = MyPlugin, component = Foreign)]
+fn inserted(mut world: DeferredWorld, _context: HookContext) {
+    world.resource_mut::<Log>().0.push("insert");
+}
+
+#[on_replace(plugin = MyPlugin, component = Foreign)]
+fn replaced(mut world: DeferredWorld, _context: HookContext) {
+    world.resource_mut::<Log>().0.push("replace");
+}
+
+#[on_remove(plugin = MyPlugin, component = Foreign)]
+fn removed(mut world: DeferredWorld, _context: HookContext) {
+    world.resource_mut::<Log>().0.push("remove");
+}
+
+#[on_despawn(plugin = MyPlugin, component = Foreign)]
+#[on_despawn(plugin = MyPlugin, component = Name)]
+fn despawned(mut world: DeferredWorld, _context: HookContext) {
+    world.resource_mut::<Log>().0.push("despawn");
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test() {
+    let mut app = App::new();
+    app.add_plugins(MyPlugin);
+    let world = app.world_mut();
+
+    let entity = world.spawn(Foreign).id();
+    world.entity_mut(entity).insert(Foreign);
+    world.entity_mut(entity).remove::<Foreign>();
+    world.entity_mut(entity).insert((Foreign, Name::new("foreign")));
+    world.entity_mut(entity).despawn();
+
+    assert_eq!(
+        world.resource::<Log>().0,
+        [
+            "add", "insert", // spawn
+            "replace", "insert", // re-insert
+            "replace", "remove", // remove
+            "add", "insert", // insert again
+            "despawn", "despawn", "replace", "remove", // despawn
+        ]
+    );
+}
@@ -0,0 +1,57 @@
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use bevy::ecs::error::{BevyError, ErrorContext};
+use bevy::prelude::*;
+use bevy_butler::*;
+use bevy_state::app::StatesPlugin;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+static PLUGIN_ERRORS: AtomicU8 = AtomicU8::new(0);
+static ENTRY_ERRORS: AtomicU8 = AtomicU8::new(0);
+
+fn plugin_handler(_error: BevyError, _context: ErrorContext) {
+    PLUGIN_ERRORS.fetch_add(1, Ordering::Relaxed);
+}
+
+fn entry_handler(_error: BevyError, _context: ErrorContext) {
+    ENTRY_ERRORS.fetch_add(1, Ordering::Relaxed);
+}
+
+#[butler_plugin(error_handler = plugin_handler)]
+struct MyPlugin;
+
+#[insert_state(plugin = MyPlugin)]
+#[derive(States, Default, Debug, PartialEq, Eq, Hash, Clone)]
+enum GameState {
+    #[default]
+    Menu,
+}
+
+#[derive(Event)]
+struct Save;
+
+#[add_system(plugin = MyPlugin, schedule = Update, error_handler = entry_handler)]
+fn failing_system() -> Result {
+    Err("system failed".into())
+}
+
+#[add_observer(plugin = MyPlugin, error_handler = entry_handler, run_if = || true)]
+fn failing_observer(_save: On<Save>) -> Result {
+    Err("observer failed".into())
+}
+
+#[on_enter(plugin = MyPlugin, state = GameState::Menu)]
+fn failing_on_enter() -> Result {
+    Err("on_enter failed".into())
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test() {
+    let mut app = App::new();
+    app.add_plugins((StatesPlugin, MyPlugin));
+    app.update();
+    app.world_mut().trigger(Save);
+
+    assert_eq!(ENTRY_ERRORS.load(Ordering::Relaxed), 2);
+    assert_eq!(PLUGIN_ERRORS.load(Ordering::Relaxed), 1);
+}
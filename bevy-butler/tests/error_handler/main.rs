@@ -0,0 +1,4 @@
+include!("../common.rs");
+
+mod entry_handler;
+mod plugin_handler;
@@ -0,0 +1,60 @@
+use std::sync::Mutex;
+
+use bevy::ecs::error::{BevyError, ErrorContext};
+use bevy::prelude::*;
+use bevy_butler::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+static ERRORS: Mutex<Vec<(&str, String)>> = Mutex::new(Vec::new());
+
+fn record(error: BevyError, context: ErrorContext) {
+    let kind = match context {
+        ErrorContext::System { .. } => "system",
+        ErrorContext::Observer { .. } => "observer",
+        _ => "other",
+    };
+    // Skip the backtrace
+    let message = error
+        .to_string()
+        .lines()
+        .next()
+        .unwrap_or_default()
+        .to_owned();
+    ERRORS.lock().unwrap().push((kind, message));
+}
+
+#[butler_plugin(error_handler = record)]
+struct MyPlugin;
+
+#[derive(Event)]
+struct Save;
+
+#[add_system(plugin = MyPlugin, schedule = Update)]
+fn failing_system() -> Result {
+    Err("system failed".into())
+}
+
+#[add_system(plugin = MyPlugin, schedule = Update)]
+fn infallible_system() {}
+
+#[add_observer(plugin = MyPlugin)]
+fn failing_observer(_save: On<Save>) -> Result {
+    Err("observer failed".into())
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test() {
+    let mut app = App::new();
+    app.add_plugins(MyPlugin);
+    app.update();
+    app.world_mut().trigger(Save);
+
+    let errors = ERRORS.lock().unwrap();
+    assert_eq!(
+        *errors,
+        [
+            ("system", "system failed".to_owned()),
+            ("observer", "observer failed".to_owned())
+        ]
+    );
+}
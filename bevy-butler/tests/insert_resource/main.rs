@@ -4,3 +4,4 @@ mod generic_resource;
 mod non_send;
 mod resource;
 mod resource_enum;
+mod scoped;
@@ -0,0 +1,60 @@
+use bevy::prelude::*;
+use bevy_butler::*;
+use bevy_state::app::StatesPlugin;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[butler_plugin]
+struct MyPlugin;
+
+#[insert_state(plugin = MyPlugin)]
+#[derive(States, Default, Debug, PartialEq, Eq, Hash, Clone)]
+enum GameState {
+    #[default]
+    Menu,
+    Playing,
+}
+
+#[derive(Resource, Default)]
+#[insert_resource(plugin = MyPlugin, scoped_to = GameState::Playing)]
+struct Score(u32);
+
+#[derive(Resource)]
+#[insert_resource(plugin = MyPlugin, scoped_to = GameState::Playing, init = Timer(30))]
+struct Timer(u32);
+
+#[derive(Default)]
+#[insert_resource(plugin = MyPlugin, scoped_to = GameState::Playing, non_send)]
+struct LevelHandle(std::marker::PhantomData<*const ()>);
+
+fn set_state(app: &mut App, state: GameState) {
+    app.world_mut()
+        .resource_mut::<NextState<GameState>>()
+        .set(state);
+    app.world_mut().run_schedule(StateTransition);
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test() {
+    let mut app = App::new();
+    app.add_plugins((StatesPlugin, MyPlugin));
+    app.world_mut().run_schedule(StateTransition);
+
+    assert!(!app.world().contains_resource::<Score>());
+    assert!(!app.world().contains_resource::<Timer>());
+    assert!(app.world().get_non_send_resource::<LevelHandle>().is_none());
+
+    set_state(&mut app, GameState::Playing);
+    assert_eq!(app.world().resource::<Score>().0, 0);
+    assert_eq!(app.world().resource::<Timer>().0, 30);
+    assert!(app.world().get_non_send_resource::<LevelHandle>().is_some());
+
+    app.world_mut().resource_mut::<Score>().0 = 10;
+
+    set_state(&mut app, GameState::Menu);
+    assert!(!app.world().contains_resource::<Score>());
+    assert!(!app.world().contains_resource::<Timer>());
+    assert!(app.world().get_non_send_resource::<LevelHandle>().is_none());
+
+    set_state(&mut app, GameState::Playing);
+    assert_eq!(app.world().resource::<Score>().0, 0);
+}
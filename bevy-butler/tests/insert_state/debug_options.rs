@@ -0,0 +1,79 @@
+use std::any::TypeId;
+
+use bevy::prelude::*;
+use bevy_butler::state::StateHistory;
+use bevy_butler::*;
+use bevy_state::app::StatesPlugin;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[butler_plugin]
+struct GamePlugin;
+
+#[insert_state(plugin = GamePlugin, log_transitions, reflect, history = 2)]
+#[derive(States, Default, Debug, PartialEq, Eq, Hash, Clone, Reflect)]
+enum GameState {
+    #[default]
+    Menu,
+    InGame,
+    Paused,
+}
+
+#[add_computed_state(plugin = GamePlugin, reflect, history = 4)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Reflect)]
+struct Playing;
+
+impl ComputedStates for Playing {
+    type SourceStates = GameState;
+
+    fn compute(sources: GameState) -> Option<Self> {
+        (sources == GameState::InGame).then_some(Playing)
+    }
+}
+
+fn set_state(app: &mut App, state: GameState) {
+    app.world_mut()
+        .resource_mut::<NextState<GameState>>()
+        .set(state);
+    app.world_mut().run_schedule(StateTransition);
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test() {
+    let mut app = App::new();
+    app.add_plugins((StatesPlugin, GamePlugin));
+    app.world_mut().run_schedule(StateTransition);
+
+    {
+        let registry = app.world().resource::<AppTypeRegistry>().read();
+        assert!(registry.contains(TypeId::of::<GameState>()));
+        assert!(registry.contains(TypeId::of::<State<GameState>>()));
+        assert!(registry.contains(TypeId::of::<NextState<GameState>>()));
+        assert!(registry.contains(TypeId::of::<State<Playing>>()));
+    }
+
+    let history = app.world().resource::<StateHistory<GameState>>();
+    assert_eq!(history.len(), 1);
+    assert_eq!(history.last().unwrap().entered, Some(GameState::Menu));
+
+    set_state(&mut app, GameState::InGame);
+    set_state(&mut app, GameState::Paused);
+
+    let history = app.world().resource::<StateHistory<GameState>>();
+    assert_eq!(history.capacity(), 2);
+    let transitions: Vec<_> = history
+        .iter()
+        .map(|record| (record.exited.clone(), record.entered.clone()))
+        .collect();
+    assert_eq!(
+        transitions,
+        [
+            (Some(GameState::Menu), Some(GameState::InGame)),
+            (Some(GameState::InGame), Some(GameState::Paused)),
+        ]
+    );
+
+    let history = app.world().resource::<StateHistory<Playing>>();
+    assert_eq!(history.len(), 2);
+    assert_eq!(history.last().unwrap().exited, Some(Playing));
+    assert_eq!(history.last().unwrap().entered, None);
+}
@@ -1,4 +1,5 @@
 include!("../common.rs");
 
+mod debug_options;
 mod insert_state;
-mod with_init;
\ No newline at end of file
+mod with_init;
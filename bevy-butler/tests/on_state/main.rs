@@ -0,0 +1,5 @@
+include!("../common.rs");
+
+mod on_enter;
+mod on_exit;
+mod on_transition;
@@ -0,0 +1,72 @@
+use bevy::prelude::*;
+use bevy_butler::*;
+use bevy_state::app::StatesPlugin;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[butler_plugin]
+struct GamePlugin;
+
+#[insert_state(plugin = GamePlugin)]
+#[derive(States, Default, Debug, PartialEq, Eq, Hash, Clone)]
+enum GameState {
+    #[default]
+    Loading,
+    Menu,
+    Paused,
+}
+
+#[derive(Resource, Default)]
+#[insert_resource(plugin = GamePlugin)]
+struct Counter(Vec<&'static str>);
+
+#[on_enter(plugin = GamePlugin, state = GameState::Loading)]
+fn enter_loading(mut counter: ResMut<Counter>) {
+    counter.0.push("loading");
+}
+
+#[on_enter(plugin = GamePlugin, state = [GameState::Menu, GameState::Paused])]
+fn enter_menu(mut counter: ResMut<Counter>) {
+    counter.0.push("menu");
+}
+
+#[on_enter(plugin = GamePlugin, state = [GameState::Menu, GameState::Paused], after = enter_menu)]
+fn enter_menu_after(mut counter: ResMut<Counter>) {
+    counter.0.push("menu_after");
+}
+
+fn source() -> &'static str {
+    "piped"
+}
+
+#[on_enter(plugin = GamePlugin, state = GameState::Paused, pipe_in = [source])]
+fn enter_paused(input: In<&'static str>, mut counter: ResMut<Counter>) {
+    counter.0.push(*input);
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test() {
+    let mut app = App::new();
+    app.add_plugins((StatesPlugin, GamePlugin));
+
+    app.world_mut().run_schedule(StateTransition);
+    assert_eq!(app.world().resource::<Counter>().0, ["loading"]);
+
+    app.world_mut().resource_mut::<Counter>().0.clear();
+    app.world_mut()
+        .resource_mut::<NextState<GameState>>()
+        .set(GameState::Menu);
+    app.world_mut().run_schedule(StateTransition);
+    assert_eq!(app.world().resource::<Counter>().0, ["menu", "menu_after"]);
+
+    app.world_mut().resource_mut::<Counter>().0.clear();
+    app.world_mut()
+        .resource_mut::<NextState<GameState>>()
+        .set(GameState::Paused);
+    app.world_mut().run_schedule(StateTransition);
+    let counter = &app.world().resource::<Counter>().0;
+    assert_eq!(counter.len(), 3);
+    assert!(counter.contains(&"piped"));
+    let menu = counter.iter().position(|s| *s == "menu").unwrap();
+    let menu_after = counter.iter().position(|s| *s == "menu_after").unwrap();
+    assert!(menu < menu_after);
+}
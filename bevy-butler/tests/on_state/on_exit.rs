@@ -0,0 +1,44 @@
+use bevy::prelude::*;
+use bevy_butler::*;
+use bevy_state::app::StatesPlugin;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[butler_plugin]
+struct GamePlugin;
+
+#[insert_state(plugin = GamePlugin)]
+#[derive(States, Default, Debug, PartialEq, Eq, Hash, Clone)]
+enum GameState {
+    #[default]
+    Menu,
+    InGame,
+}
+
+#[derive(Resource, Default)]
+#[insert_resource(plugin = GamePlugin)]
+struct Exits(u8);
+
+#[on_exit(plugin = GamePlugin, state = [GameState::Menu, GameState::InGame])]
+fn count_exits(mut exits: ResMut<Exits>) {
+    exits.0 += 1;
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test() {
+    let mut app = App::new();
+    app.add_plugins((StatesPlugin, GamePlugin));
+    app.world_mut().run_schedule(StateTransition);
+    assert_eq!(app.world().resource::<Exits>().0, 0);
+
+    app.world_mut()
+        .resource_mut::<NextState<GameState>>()
+        .set(GameState::InGame);
+    app.world_mut().run_schedule(StateTransition);
+    assert_eq!(app.world().resource::<Exits>().0, 1);
+
+    app.world_mut()
+        .resource_mut::<NextState<GameState>>()
+        .set(GameState::Menu);
+    app.world_mut().run_schedule(StateTransition);
+    assert_eq!(app.world().resource::<Exits>().0, 2);
+}
@@ -0,0 +1,45 @@
+use bevy::prelude::*;
+use bevy_butler::*;
+use bevy_state::app::StatesPlugin;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[butler_plugin]
+struct GamePlugin;
+
+#[insert_state(plugin = GamePlugin)]
+#[derive(States, Default, Debug, PartialEq, Eq, Hash, Clone)]
+enum GameState {
+    #[default]
+    Menu,
+    InGame,
+    Paused,
+}
+
+#[derive(Resource, Default)]
+#[insert_resource(plugin = GamePlugin)]
+struct Transitions(u8);
+
+#[on_transition(plugin = GamePlugin, from = GameState::Menu, to = GameState::InGame, run_if = || true)]
+fn menu_to_game(mut transitions: ResMut<Transitions>) {
+    transitions.0 += 1;
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test() {
+    let mut app = App::new();
+    app.add_plugins((StatesPlugin, GamePlugin));
+    app.world_mut().run_schedule(StateTransition);
+
+    for (state, expected) in [
+        (GameState::InGame, 1),
+        (GameState::Paused, 1),
+        (GameState::Menu, 1),
+        (GameState::InGame, 2),
+    ] {
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(state);
+        app.world_mut().run_schedule(StateTransition);
+        assert_eq!(app.world().resource::<Transitions>().0, expected);
+    }
+}
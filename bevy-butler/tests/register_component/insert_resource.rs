@@ -0,0 +1,47 @@
+use bevy::prelude::*;
+use bevy_butler::component::ButlerComponentManifest;
+use bevy_butler::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[butler_plugin]
+struct MyPlugin;
+
+#[derive(Component)]
+#[register_component(plugin = MyPlugin)]
+struct Alpha;
+
+// Inserting the resource registers it too, which must not happen before the ids are assigned
+#[derive(Resource)]
+#[insert_resource(plugin = MyPlugin, init = Beta)]
+#[register_component(plugin = MyPlugin, resource)]
+struct Beta;
+
+#[derive(Resource)]
+#[register_component(plugin = MyPlugin, resource)]
+#[insert_resource(plugin = MyPlugin, init = Gamma)]
+struct Gamma;
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test() {
+    let mut app = App::new();
+    app.add_plugins(MyPlugin);
+    let world = app.world();
+    let manifest = world.resource::<ButlerComponentManifest>();
+
+    let names: Vec<_> = manifest.iter().map(|entry| entry.type_name).collect();
+    assert_eq!(
+        names,
+        [
+            std::any::type_name::<Alpha>(),
+            std::any::type_name::<Beta>(),
+            std::any::type_name::<Gamma>(),
+        ]
+    );
+    assert!(manifest.iter().is_sorted_by_key(|entry| entry.id));
+    assert_eq!(
+        world.components().resource_id::<Beta>(),
+        Some(manifest.get_type::<Beta>().unwrap().id)
+    );
+    assert!(world.contains_resource::<Beta>());
+    assert!(world.contains_resource::<Gamma>());
+}
@@ -0,0 +1,5 @@
+include!("../common.rs");
+
+mod insert_resource;
+mod manifest;
+mod sub_app;
@@ -0,0 +1,82 @@
+use bevy::prelude::*;
+use bevy_butler::component::ButlerComponentManifest;
+use bevy_butler::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[butler_plugin]
+struct MyPlugin;
+
+#[derive(Component)]
+#[register_component(plugin = MyPlugin)]
+struct Zebra;
+
+#[derive(Component)]
+#[register_component(plugin = MyPlugin)]
+struct Apple;
+
+#[derive(Resource)]
+#[register_component(plugin = MyPlugin, resource)]
+struct Mango;
+
+#[butler_plugin]
+struct OtherPlugin;
+
+#[register_component(plugin = OtherPlugin)]
+type AlsoApple = Apple;
+
+#[derive(Component)]
+#[register_component(plugin = OtherPlugin)]
+struct Banana;
+
+fn build_app() -> App {
+    let mut app = App::new();
+    // Use some other components first, which would normally shift the ids
+    app.world_mut().spawn((Name::new("first"), Transform::default()));
+    app.add_plugins((MyPlugin, OtherPlugin));
+    app
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn sorted_by_type_name() {
+    let app = build_app();
+    let world = app.world();
+    let manifest = world.resource::<ButlerComponentManifest>();
+
+    let names: Vec<_> = manifest.iter().map(|entry| entry.type_name).collect();
+    assert_eq!(
+        names,
+        [
+            std::any::type_name::<Apple>(),
+            std::any::type_name::<Mango>(),
+            std::any::type_name::<Zebra>(),
+            std::any::type_name::<Banana>(),
+        ]
+    );
+    assert!(manifest.iter().is_sorted_by_key(|entry| entry.id));
+
+    let mango = manifest.get_type::<Mango>().unwrap();
+    assert!(mango.resource);
+    assert_eq!(world.components().resource_id::<Mango>(), Some(mango.id));
+    assert_eq!(
+        world.components().component_id::<Apple>(),
+        Some(manifest.get_type::<Apple>().unwrap().id)
+    );
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn deterministic() {
+    let first = build_app();
+    let second = build_app();
+    assert_eq!(
+        first
+            .world()
+            .resource::<ButlerComponentManifest>()
+            .iter()
+            .collect::<Vec<_>>(),
+        second
+            .world()
+            .resource::<ButlerComponentManifest>()
+            .iter()
+            .collect::<Vec<_>>(),
+    );
+}
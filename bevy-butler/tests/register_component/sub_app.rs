@@ -0,0 +1,43 @@
+use bevy::prelude::*;
+use bevy_app::AppLabel;
+use bevy_butler::component::ButlerComponentManifest;
+use bevy_butler::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[butler_plugin]
+struct MyPlugin;
+
+// Declared on both sides of the sub app, so one of them is linked before it with either registry backend
+#[derive(Component)]
+#[register_component(plugin = MyPlugin, sub_app = SimulationApp)]
+struct Alpha;
+
+#[derive(AppLabel, Debug, Clone, PartialEq, Eq, Hash)]
+#[butler_sub_app(plugin = MyPlugin)]
+struct SimulationApp;
+
+#[derive(Component)]
+#[register_component(plugin = MyPlugin, sub_app = SimulationApp)]
+struct Beta;
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test() {
+    let mut app = App::new();
+    app.add_plugins(MyPlugin);
+    assert!(!app.world().contains_resource::<ButlerComponentManifest>());
+
+    let world = app.sub_app(SimulationApp).world();
+    let manifest = world.resource::<ButlerComponentManifest>();
+    let names: Vec<_> = manifest.iter().map(|entry| entry.type_name).collect();
+    assert_eq!(
+        names,
+        [
+            std::any::type_name::<Alpha>(),
+            std::any::type_name::<Beta>()
+        ]
+    );
+    assert_eq!(
+        world.components().component_id::<Beta>(),
+        Some(manifest.get_type::<Beta>().unwrap().id)
+    );
+}
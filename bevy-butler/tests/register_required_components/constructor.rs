@@ -0,0 +1,30 @@
+use bevy::prelude::*;
+use bevy_butler::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[butler_plugin]
+struct MyPlugin;
+
+#[derive(Component, Debug, PartialEq)]
+struct Health(u32);
+
+fn full_health() -> Health {
+    Health(100)
+}
+
+#[derive(Component)]
+#[register_required_components(plugin = MyPlugin, requires = Health, constructor = full_health)]
+#[register_required_components(plugin = MyPlugin, requires = Name, constructor = || Name::new("Enemy"))]
+struct Enemy;
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test() {
+    let mut app = App::new();
+    app.add_plugins(MyPlugin);
+    let world = app.world_mut();
+
+    let entity = world.spawn(Enemy).id();
+    let entity = world.entity(entity);
+    assert_eq!(entity.get::<Health>(), Some(&Health(100)));
+    assert_eq!(entity.get::<Name>().map(Name::as_str), Some("Enemy"));
+}
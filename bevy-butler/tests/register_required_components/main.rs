@@ -0,0 +1,4 @@
+include!("../common.rs");
+
+mod constructor;
+mod requires;
@@ -0,0 +1,41 @@
+use bevy::prelude::*;
+use bevy_butler::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[butler_plugin]
+struct MyPlugin;
+
+mod foreign {
+    use bevy::prelude::*;
+
+    #[derive(Component, Default)]
+    pub struct RigidBody;
+
+    #[derive(Component)]
+    pub struct Collider;
+}
+
+#[derive(Component, Default, Debug, PartialEq)]
+struct Mass(u32);
+
+#[derive(Component, Default)]
+struct Velocity;
+
+#[register_required_components(plugin = MyPlugin, requires = [Mass, Velocity])]
+use foreign::RigidBody;
+
+#[register_required_components(plugin = MyPlugin, requires = RigidBody)]
+use foreign::Collider;
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test() {
+    let mut app = App::new();
+    app.add_plugins(MyPlugin);
+    let world = app.world_mut();
+
+    let entity = world.spawn(Collider).id();
+    let entity = world.entity(entity);
+    assert!(entity.contains::<RigidBody>());
+    assert_eq!(entity.get::<Mass>(), Some(&Mass(0)));
+    assert!(entity.contains::<Velocity>());
+}
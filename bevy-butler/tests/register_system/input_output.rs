@@ -0,0 +1,39 @@
+use bevy::prelude::*;
+use bevy_butler::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[butler_plugin]
+struct MyPlugin;
+
+#[register_system(plugin = MyPlugin)]
+fn double(In(value): In<u32>) -> u32 {
+    value * 2
+}
+
+#[register_system(plugin = MyPlugin)]
+fn push_value(InMut(values): InMut<'_, Vec<u32>>) {
+    values.push(5);
+}
+
+#[register_system(plugin = MyPlugin)]
+fn sum(InRef(values): InRef<'_, [u32]>) -> u32 {
+    values.iter().sum()
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test() {
+    let mut app = App::new();
+    app.add_plugins(MyPlugin);
+    let world = app.world_mut();
+
+    let double = *world.resource::<Double>();
+    assert_eq!(world.run_system_with(double.0, 4).unwrap(), 8);
+
+    let mut values = vec![1, 2];
+    let push = *world.resource::<PushValue>();
+    world.run_system_with(push.0, &mut values).unwrap();
+    assert_eq!(values, [1, 2, 5]);
+
+    let sum = *world.resource::<Sum>();
+    assert_eq!(world.run_system_with(sum.0, &values).unwrap(), 8);
+}
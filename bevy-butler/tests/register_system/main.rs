@@ -0,0 +1,4 @@
+include!("../common.rs");
+
+mod input_output;
+mod register_system;
@@ -0,0 +1,39 @@
+use bevy::prelude::*;
+use bevy_butler::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[butler_plugin]
+struct MyPlugin;
+
+#[derive(Resource, Default)]
+#[insert_resource(plugin = MyPlugin)]
+struct Waves(u8);
+
+#[register_system(plugin = MyPlugin)]
+fn spawn_wave(mut waves: ResMut<Waves>) {
+    waves.0 += 1;
+}
+
+#[register_system(plugin = MyPlugin, handle = ResetHandle)]
+fn reset_waves(mut waves: ResMut<Waves>) {
+    waves.0 = 0;
+}
+
+#[add_system(plugin = MyPlugin, schedule = Update)]
+fn run_spawn(mut commands: Commands, spawn: Res<SpawnWave>) {
+    commands.run_system(spawn.0);
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test() {
+    let mut app = App::new();
+    app.add_plugins(MyPlugin);
+
+    app.update();
+    app.update();
+    assert_eq!(app.world().resource::<Waves>().0, 2);
+
+    let reset = *app.world().resource::<ResetHandle>();
+    app.world_mut().run_system(reset.0).unwrap();
+    assert_eq!(app.world().resource::<Waves>().0, 0);
+}
@@ -0,0 +1,46 @@
+//! This test ensures that entries can be submitted through the public registry API, like a third-party macro would
+
+use bevy_app::prelude::*;
+use bevy_butler::registry::{ButlerEntry, ButlerEntryInfo, ButlerPhase, ButlerPluginMarker};
+use bevy_butler::*;
+use bevy_ecs::prelude::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+use crate::common::log_plugin;
+
+#[butler_plugin]
+struct MyPlugin;
+
+#[derive(Resource, Default)]
+struct Widgets(Vec<&'static str>);
+
+bevy_butler::butler_entry!(
+    _widget_registry,
+    ButlerEntry::new(MyPlugin::butler_plugin_marker, |app| {
+        app.init_resource::<Widgets>();
+    })
+);
+
+bevy_butler::butler_entry!(
+    _late_widget,
+    ButlerEntry::new(MyPlugin::butler_plugin_marker, |app| {
+        app.world_mut().resource_mut::<Widgets>().0.push("button");
+    })
+    .in_phase(ButlerPhase::Finish)
+    .with_info(ButlerEntryInfo {
+        kind: "widget",
+        name: "button",
+        file: file!(),
+        line: line!(),
+    })
+);
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test() {
+    let mut app = App::new();
+    app.add_plugins((log_plugin(), MyPlugin));
+
+    assert!(app.world().resource::<Widgets>().0.is_empty());
+    app.finish();
+    assert_eq!(app.world().resource::<Widgets>().0, ["button"]);
+}
@@ -0,0 +1,4 @@
+include!("../common.rs");
+
+mod missing;
+mod sub_app;
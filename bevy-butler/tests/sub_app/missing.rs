@@ -0,0 +1,32 @@
+use bevy::prelude::*;
+use bevy_app::AppLabel;
+use bevy_butler::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[butler_plugin]
+struct MyPlugin;
+
+#[derive(AppLabel, Debug, Clone, PartialEq, Eq, Hash)]
+struct SimulationApp;
+
+#[derive(Resource, Default)]
+#[insert_resource(plugin = MyPlugin, sub_app = SimulationApp)]
+struct Counter(u32);
+
+#[wasm_bindgen_test(unsupported = test)]
+#[should_panic(expected = "The sub app `SimulationApp` doesn't exist")]
+fn panics() {
+    App::new().add_plugins(MyPlugin);
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn inserted_manually() {
+    let mut app = App::new();
+    app.insert_sub_app(SimulationApp, SubApp::new());
+    app.add_plugins(MyPlugin);
+
+    assert_eq!(
+        app.sub_app(SimulationApp).world().resource::<Counter>().0,
+        0
+    );
+}
@@ -0,0 +1,59 @@
+use bevy::prelude::*;
+use bevy_app::AppLabel;
+use bevy_butler::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+use crate::common::log_plugin;
+
+#[butler_plugin]
+struct MyPlugin;
+
+#[derive(AppLabel, Debug, Clone, PartialEq, Eq, Hash)]
+#[butler_sub_app(plugin = MyPlugin, extract = extract_counter, update_schedule = Update)]
+struct SimulationApp;
+
+#[derive(Resource, Default)]
+#[insert_resource(plugin = MyPlugin)]
+#[insert_resource(plugin = MyPlugin, sub_app = SimulationApp)]
+struct Counter(u32);
+
+#[derive(Resource, Default)]
+#[insert_resource(plugin = MyPlugin, sub_app = SimulationApp)]
+struct Mirror(u32);
+
+#[derive(Message)]
+#[add_message(plugin = MyPlugin, sub_app = SimulationApp)]
+struct Doubled;
+
+#[add_system(plugin = MyPlugin, schedule = Update)]
+fn increment(mut counter: ResMut<Counter>) {
+    counter.0 += 1;
+}
+
+fn extract_counter(main_world: &mut World, sub_world: &mut World) {
+    sub_world.resource_mut::<Mirror>().0 = main_world.resource::<Counter>().0;
+}
+
+#[add_system(plugin = MyPlugin, schedule = Update, sub_app = SimulationApp)]
+fn double(mirror: Res<Mirror>, mut counter: ResMut<Counter>, mut doubled: MessageWriter<Doubled>) {
+    counter.0 = mirror.0 * 2;
+    doubled.write(Doubled);
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test() {
+    let mut app = App::new();
+    app.add_plugins((log_plugin(), MyPlugin));
+    app.finish();
+    app.cleanup();
+
+    app.update();
+    app.update();
+
+    assert_eq!(app.world().resource::<Counter>().0, 2);
+    assert!(app.world().get_resource::<Mirror>().is_none());
+
+    let sub_app = app.sub_app(SimulationApp);
+    assert_eq!(sub_app.world().resource::<Mirror>().0, 2);
+    assert_eq!(sub_app.world().resource::<Counter>().0, 4);
+}